@@ -0,0 +1,177 @@
+use macroquad::*;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use crate::animation::Easing;
+use crate::background::lerp_color;
+use crate::piece::Piece;
+
+/// Visual properties applied to a `Piece` while it is in a given state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Look {
+    /// Tint of the piece
+    pub tint: Color,
+
+    /// Opacity of the piece from `0.0` (invisible) to `1.0` (opaque)
+    pub alpha: f32,
+
+    /// Rotation of the piece in radians
+    pub rotation: f32,
+
+    /// Draw order of the piece relative to its siblings
+    pub z_index: i32,
+
+    /// Seconds taken to blend from the previous look into this one
+    pub duration: f32,
+
+    /// Easing curve of the blend
+    pub easing: Easing,
+}
+
+impl Default for Look {
+    fn default() -> Self {
+        Look {
+            tint: WHITE,
+            alpha: 1.0,
+            rotation: 0.0,
+            z_index: 0,
+            duration: 0.0,
+            easing: Easing::Linear
+        }
+    }
+}
+
+impl Look {
+    /// Blend from `self` to `other` by `t` in `[0.0, 1.0]`. The z-index switches immediately so
+    /// a piece being raised is on top for the entire blend.
+    fn blend(&self, other: &Look, t: f32) -> Look {
+        Look {
+            tint: lerp_color(self.tint, other.tint, t),
+            alpha: self.alpha + (other.alpha - self.alpha) * t,
+            rotation: self.rotation + (other.rotation - self.rotation) * t,
+            z_index: other.z_index,
+            duration: other.duration,
+            easing: other.easing
+        }
+    }
+}
+
+/// State machine declaring how a piece looks in each of the game defined states `S` (such as
+/// idle, selected, moving, captured) and which transitions between them are allowed.
+///
+/// ```
+/// let mut state = AnimState::new(State::Idle);
+/// state.look(State::Idle, Look::default());
+/// state.look(State::Selected, Look { tint: GOLD, z_index: 1, duration: 0.15, ..Look::default() });
+/// state.look(State::Captured, Look { alpha: 0.0, duration: 0.4, ..Look::default() });
+/// state.allow(State::Idle, State::Selected);
+/// state.allow(State::Selected, State::Idle);
+/// state.allow(State::Selected, State::Captured);
+///
+/// // Each frame
+/// state.tick(get_frame_time());
+/// state.apply(&mut piece);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimState<S: Copy + Eq + Hash> {
+    /// Current state
+    current: S,
+
+    /// Look for each declared state. States without a look use `Look::default()`.
+    looks: HashMap<S, Look>,
+
+    /// Allowed (from, to) transitions
+    transitions: HashSet<(S, S)>,
+
+    /// Look the piece had when the current transition started
+    from: Look,
+
+    /// Seconds elapsed since the current transition started
+    elapsed: f32,
+}
+
+impl<S: Copy + Eq + Hash> AnimState<S> {
+    /// Create a new state machine starting in `initial`
+    pub fn new(initial: S) -> Self {
+        AnimState {
+            current: initial,
+            looks: HashMap::new(),
+            transitions: HashSet::new(),
+            from: Look::default(),
+            elapsed: 0.0
+        }
+    }
+
+    /// Declare the look of `state`
+    pub fn look(&mut self, state: S, look: Look) {
+        if state == self.current {
+            self.from = look;
+        }
+
+        self.looks.insert(state, look);
+    }
+
+    /// Allow transitioning from `from` to `to`
+    pub fn allow(&mut self, from: S, to: S) {
+        self.transitions.insert((from, to));
+    }
+
+    /// Get the current state
+    pub fn current(&self) -> S {
+        self.current
+    }
+
+    /// Returns `true` if the current state can transition to `to`
+    pub fn can_transition(&self, to: S) -> bool {
+        self.transitions.contains(&(self.current, to))
+    }
+
+    /// Transition to `to`, blending from the current look into the look of `to`. Returns `false`
+    /// without changing state if the transition is not allowed.
+    pub fn transition(&mut self, to: S) -> bool {
+        if !self.can_transition(to) {
+            return false;
+        }
+
+        // Start from wherever the previous blend currently is so interrupted transitions don't
+        // jump
+        self.from = self.current_look();
+        self.current = to;
+        self.elapsed = 0.0;
+        true
+    }
+
+    /// Returns `true` while blending into the look of the current state
+    pub fn is_transitioning(&self) -> bool {
+        self.elapsed < self.target().duration
+    }
+
+    /// Advance the current transition by `dt` seconds
+    pub fn tick(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.target().duration);
+    }
+
+    /// Get the look of the current state
+    fn target(&self) -> Look {
+        self.looks.get(&self.current).copied().unwrap_or_default()
+    }
+
+    /// Get the look at the current point of the transition
+    pub fn current_look(&self) -> Look {
+        let target = self.target();
+        if target.duration <= 0.0 {
+            return target;
+        }
+
+        let t = target.easing.apply(self.elapsed / target.duration);
+        self.from.blend(&target, t)
+    }
+
+    /// Apply the current look to `piece`
+    pub fn apply(&self, piece: &mut Piece) {
+        let look = self.current_look();
+        piece.set_tint(look.tint);
+        piece.set_alpha(look.alpha);
+        piece.set_rotation(look.rotation);
+        piece.set_z_index(look.z_index);
+    }
+}
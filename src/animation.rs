@@ -0,0 +1,517 @@
+use macroquad::*;
+use std::collections::VecDeque;
+use crate::Resizeable;
+use crate::piece::Piece;
+
+/// Easing curve used to interpolate a `Tween`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+
+    /// Start slow and speed up
+    EaseIn,
+
+    /// Start fast and slow down
+    EaseOut,
+
+    /// Start slow, speed up, then slow down
+    EaseInOut,
+}
+
+impl Easing {
+    /// Apply the easing curve to the linear progress `t` in `[0.0, 1.0]`
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+        }
+    }
+}
+
+/// Interpolation of a location from `from` to `to` over `duration` seconds
+#[derive(Debug, Clone, Copy)]
+pub struct Tween {
+    /// Starting location
+    from: Vec2,
+
+    /// Ending location
+    to: Vec2,
+
+    /// Length of the tween in seconds
+    duration: f32,
+
+    /// Seconds elapsed since the start of the tween
+    elapsed: f32,
+
+    /// Easing curve of the tween
+    easing: Easing,
+}
+
+impl Tween {
+    /// Create a new `Tween` from `from` to `to` over `duration` seconds using `easing`
+    pub fn new(from: Vec2, to: Vec2, duration: f32, easing: Easing) -> Self {
+        Tween {
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing
+        }
+    }
+
+    /// Advance the tween by `dt` seconds
+    pub fn tick(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+    }
+
+    /// Get the linear progress of the tween in `[0.0, 1.0]`
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+
+        self.elapsed / self.duration
+    }
+
+    /// Get the current interpolated location
+    pub fn location(&self) -> Vec2 {
+        self.location_at(self.elapsed)
+    }
+
+    /// Get the interpolated location `elapsed` seconds into the tween
+    fn location_at(&self, elapsed: f32) -> Vec2 {
+        if self.duration <= 0.0 {
+            return self.to;
+        }
+
+        let t = self.easing.apply((elapsed / self.duration).max(0.0).min(1.0));
+        self.from + (self.to - self.from) * t
+    }
+
+    /// Returns `true` once the tween has reached its ending location
+    pub fn finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Jump to the ending location
+    pub fn finish(&mut self) {
+        self.elapsed = self.duration;
+    }
+}
+
+/// Fading afterimages drawn behind a piece while it moves, making fast moves easier to follow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trail {
+    /// Number of afterimages
+    pub count: usize,
+
+    /// Seconds between each afterimage along the tween
+    pub spacing: f32,
+
+    /// Opacity of the afterimage closest to the piece. Each following afterimage fades evenly
+    /// towards invisible.
+    pub alpha: f32,
+}
+
+impl Default for Trail {
+    fn default() -> Self {
+        Trail {
+            count: 4,
+            spacing: 0.03,
+            alpha: 0.5
+        }
+    }
+}
+
+/// `Piece` being moved by a `Tween`
+struct Animation {
+    /// ID returned when the animation was started
+    id: usize,
+
+    /// Piece being animated
+    piece: Piece,
+
+    /// Tween driving the location of the piece
+    tween: Tween,
+
+    /// Resize adjustment the piece is drawn with
+    adjustment: f32,
+
+    /// Multiplier applied to the elapsed time of each tick
+    speed: f32,
+
+    /// Afterimages drawn behind the piece, along with a copy of the piece for each afterimage
+    /// already faded to its opacity, from closest to the piece to furthest
+    trail: Option<(Trail, Vec<Piece>)>,
+
+    /// Callback invoked once the animation finishes
+    on_complete: Option<Box<dyn FnOnce()>>,
+}
+
+/// Drives all of the currently animating pieces
+///
+/// ```
+/// let id = animator.animate(checker, Tween::new(from, to, 0.3, Easing::EaseInOut), adjustment);
+///
+/// // Each frame
+/// for (id, piece) in animator.tick(get_frame_time()) {
+///     grid.set(row, col, piece);
+/// }
+/// animator.draw();
+/// ```
+#[derive(Default)]
+pub struct Animator {
+    /// ID given to the next animation
+    next_id: usize,
+
+    /// Currently running animations
+    animations: Vec<Animation>,
+}
+
+impl Animator {
+    /// Initialize an `Animator` with no running animations
+    pub fn new() -> Self {
+        Animator::default()
+    }
+
+    /// Start moving `piece` along `tween`, drawn resized using `adjustment`. Returns the ID of
+    /// the animation.
+    pub fn animate(&mut self, piece: Piece, tween: Tween, adjustment: f32) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.animations.push(Animation {
+            id,
+            piece,
+            tween,
+            adjustment,
+            speed: 1.0,
+            trail: None,
+            on_complete: None
+        });
+
+        id
+    }
+
+    /// Set a callback invoked when the animation `id` finishes
+    pub fn on_complete<F: FnOnce() + 'static>(&mut self, id: usize, callback: F) {
+        if let Some(animation) = self.animations.iter_mut().find(|anim| anim.id == id) {
+            animation.on_complete = Some(Box::new(callback));
+        }
+    }
+
+    /// Returns `true` if the animation `id` is still running
+    pub fn is_running(&self, id: usize) -> bool {
+        self.animations.iter().any(|anim| anim.id == id)
+    }
+
+    /// Set how fast the animation `id` plays, where `2.0` plays twice as fast
+    pub fn set_speed(&mut self, id: usize, speed: f32) {
+        if let Some(animation) = self.animations.iter_mut().find(|anim| anim.id == id) {
+            animation.speed = speed;
+        }
+    }
+
+    /// Draw `trail` behind the piece of the animation `id` while it moves
+    pub fn set_trail(&mut self, id: usize, trail: Trail) {
+        if let Some(animation) = self.animations.iter_mut().find(|anim| anim.id == id) {
+            // Fade each afterimage once here rather than cloning the piece every frame
+            let afterimages = (1..=trail.count).map(|i| {
+                let fade = 1.0 - (i - 1) as f32 / trail.count as f32;
+                let mut afterimage = animation.piece.clone();
+                afterimage.set_alpha(animation.piece.alpha() * trail.alpha * fade);
+                afterimage
+            }).collect();
+
+            animation.trail = Some((trail, afterimages));
+        }
+    }
+
+    /// Jump the animation `id` to its ending location. It is returned by the next `tick`.
+    pub fn finish(&mut self, id: usize) {
+        if let Some(animation) = self.animations.iter_mut().find(|anim| anim.id == id) {
+            animation.tween.finish();
+        }
+    }
+
+    /// Returns `true` if any animation has reached its end and will be returned by the next
+    /// `tick`
+    fn has_finished(&self) -> bool {
+        self.animations.iter().any(|anim| anim.tween.finished())
+    }
+
+    /// Returns `true` if any animation is still running
+    pub fn is_animating(&self) -> bool {
+        !self.animations.is_empty()
+    }
+
+    /// Advance every animation by `dt` seconds. Finished animations invoke their callbacks and
+    /// are returned along with their piece so game logic can place the piece at its destination.
+    pub fn tick(&mut self, dt: f32) -> Vec<(usize, Piece)> {
+        for animation in self.animations.iter_mut() {
+            animation.tween.tick(dt * animation.speed);
+        }
+
+        // Remove the finished animations, keeping the running ones in order
+        let (finished, running): (Vec<Animation>, Vec<Animation>) =
+            self.animations.drain(..).partition(|anim| anim.tween.finished());
+        self.animations = running;
+
+        finished.into_iter().map(|animation| {
+            if let Some(callback) = animation.on_complete {
+                callback();
+            }
+
+            (animation.id, animation.piece)
+        }).collect()
+    }
+
+    /// Draw every animating piece at its current location, behind any trail
+    pub fn draw(&self) {
+        for animation in self.animations.iter() {
+            if let Some((trail, afterimages)) = &animation.trail {
+                // Draw the oldest afterimage first so newer ones are on top
+                for (i, afterimage) in afterimages.iter().enumerate().rev() {
+                    let elapsed = animation.tween.elapsed - trail.spacing * (i + 1) as f32;
+                    if elapsed <= 0.0 {
+                        continue;
+                    }
+
+                    afterimage.draw(animation.tween.location_at(elapsed), animation.adjustment);
+                }
+            }
+
+            animation.piece.draw(animation.tween.location(), animation.adjustment);
+        }
+    }
+}
+
+/// How an animation in an `AnimationQueue` interacts with the other animations
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QueuePolicy {
+    /// Wait for every running animation to finish, then run alone. Concurrent animations start
+    /// as soon as no blocking animation is running.
+    pub blocking: bool,
+
+    /// Jump to the end when the queue is skipped, such as when the player clicks, and speed up
+    /// while the queue is fast-forwarded. Use for moves the player doesn't need to watch, such as
+    /// opponent turns.
+    pub skippable: bool,
+
+    /// Animations with the same key that are still waiting in the queue are collapsed into one
+    /// from the start of the first to the end of the last, such as successive moves of the same
+    /// piece
+    pub collapse_key: Option<u64>,
+
+    /// Afterimages drawn behind the piece while it moves
+    pub trail: Option<Trail>,
+}
+
+/// Animation waiting in an `AnimationQueue`
+struct Queued {
+    /// ID returned when the animation was queued
+    id: usize,
+
+    /// Piece to animate
+    piece: Piece,
+
+    /// Tween driving the location of the piece
+    tween: Tween,
+
+    /// Resize adjustment the piece is drawn with
+    adjustment: f32,
+
+    /// How the animation interacts with the other animations
+    policy: QueuePolicy,
+}
+
+/// Central queue of game driven animations, so rapid successive moves play in order instead of
+/// overlapping and can be fast-forwarded by the player
+///
+/// ```
+/// let policy = QueuePolicy {
+///     blocking: true,
+///     skippable: true,
+///     collapse_key: Some(piece_id),
+///     trail: Some(Trail::default()),
+/// };
+/// queue.push(checker, Tween::new(from, to, 0.3, Easing::EaseInOut), adjustment, policy);
+///
+/// // Each frame
+/// if is_mouse_button_pressed(MouseButton::Left) {
+///     queue.skip();
+/// }
+/// queue.fast_forward(if is_key_down(KeyCode::Space) { 4.0 } else { 1.0 });
+/// for (id, piece) in queue.tick(get_frame_time()) {
+///     grid.set(row, col, piece);
+/// }
+/// queue.draw();
+/// ```
+pub struct AnimationQueue {
+    /// Animator driving the started animations
+    animator: Animator,
+
+    /// Animations waiting to start, in order
+    pending: VecDeque<Queued>,
+
+    /// Queue ID and policy of each started animation by its `Animator` ID
+    running: Vec<(usize, usize, QueuePolicy)>,
+
+    /// ID given to the next queued animation
+    next_id: usize,
+
+    /// Speed of skippable animations
+    fast_forward: f32,
+}
+
+impl Default for AnimationQueue {
+    fn default() -> Self {
+        AnimationQueue {
+            animator: Animator::new(),
+            pending: VecDeque::new(),
+            running: Vec::new(),
+            next_id: 0,
+            fast_forward: 1.0
+        }
+    }
+}
+
+impl AnimationQueue {
+    /// Initialize an empty `AnimationQueue`
+    pub fn new() -> Self {
+        AnimationQueue::default()
+    }
+
+    /// Play every running and waiting skippable animation at `speed`, such as `4.0` while the
+    /// player holds a fast-forward key and `1.0` once it is released
+    pub fn fast_forward(&mut self, speed: f32) {
+        self.fast_forward = speed;
+
+        for (animator_id, _, policy) in self.running.iter() {
+            if policy.skippable {
+                self.animator.set_speed(*animator_id, speed);
+            }
+        }
+    }
+
+    /// Queue moving `piece` along `tween`, drawn resized using `adjustment`. Returns the ID of
+    /// the animation. If it collapses into an animation already waiting, that animation is
+    /// replaced and only the returned ID is reported by `tick`.
+    pub fn push(&mut self, piece: Piece, mut tween: Tween, adjustment: f32,
+                policy: QueuePolicy) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        if let Some(key) = policy.collapse_key {
+            let existing = self.pending.iter()
+                                       .position(|queued| queued.policy.collapse_key == Some(key));
+
+            if let Some(index) = existing {
+                // Start from where the earlier animation started, keeping its place in the queue
+                let queued = &mut self.pending[index];
+                tween.from = queued.tween.from;
+                *queued = Queued { id, piece, tween, adjustment, policy };
+                return id;
+            }
+        }
+
+        self.pending.push_back(Queued { id, piece, tween, adjustment, policy });
+        id
+    }
+
+    /// Returns `true` while any animation is running or waiting
+    pub fn is_busy(&self) -> bool {
+        !self.pending.is_empty() || !self.running.is_empty()
+    }
+
+    /// Jump every running and waiting skippable animation to its end
+    pub fn skip(&mut self) {
+        for (animator_id, _, policy) in self.running.iter() {
+            if policy.skippable {
+                self.animator.finish(*animator_id);
+            }
+        }
+
+        for queued in self.pending.iter_mut() {
+            if queued.policy.skippable {
+                queued.tween.finish();
+            }
+        }
+    }
+
+    /// Start the waiting animations allowed to run alongside the running animations
+    fn start_pending(&mut self) {
+        while let Some(next) = self.pending.front() {
+            let blocked = if next.policy.blocking {
+                !self.running.is_empty()
+            } else {
+                self.running.iter().any(|(_, _, policy)| policy.blocking)
+            };
+
+            if blocked {
+                break;
+            }
+
+            let queued = self.pending.pop_front().unwrap();
+            let animator_id = self.animator.animate(queued.piece, queued.tween,
+                                                    queued.adjustment);
+            if queued.policy.skippable {
+                self.animator.set_speed(animator_id, self.fast_forward);
+            }
+            if let Some(trail) = queued.policy.trail {
+                self.animator.set_trail(animator_id, trail);
+            }
+            self.running.push((animator_id, queued.id, queued.policy));
+
+            // Blocking animations run alone
+            if queued.policy.blocking {
+                break;
+            }
+        }
+    }
+
+    /// Advance the running animations by `dt` seconds and start any waiting animations that
+    /// are now allowed to run. Finished animations are returned along with their piece.
+    ///
+    /// Animations that start already finished, such as skipped ones, are finished in the same
+    /// call, so skipping a long queue of blocking moves completes in a single frame.
+    pub fn tick(&mut self, dt: f32) -> Vec<(usize, Piece)> {
+        let mut finished = Vec::new();
+        let mut dt = dt;
+
+        loop {
+            for (animator_id, piece) in self.animator.tick(dt) {
+                if let Some(index) = self.running.iter()
+                                                 .position(|(id, _, _)| *id == animator_id) {
+                    let (_, id, _) = self.running.remove(index);
+                    finished.push((id, piece));
+                }
+            }
+
+            self.start_pending();
+
+            // Only the first pass advances time. Keep going while newly started animations are
+            // already at their end.
+            dt = 0.0;
+            if !self.animator.has_finished() {
+                break;
+            }
+        }
+
+        finished
+    }
+
+    /// Draw every running animation at its current location
+    pub fn draw(&self) {
+        self.animator.draw();
+    }
+}
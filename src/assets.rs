@@ -3,3 +3,122 @@ use once_cell::sync::OnceCell;
 use std::collections::HashMap;
 
 pub static ASSETS: OnceCell<HashMap<u32, Texture2D>> = OnceCell::new();
+
+/// Texture IDs in `ASSETS` for each texture name registered with an `AssetLoader`
+pub static ASSET_NAMES: OnceCell<HashMap<String, u32>> = OnceCell::new();
+
+/// Source rectangle in the atlas texture for each texture ID that refers to an atlas region
+/// rather than an entire texture
+pub static ASSET_REGIONS: OnceCell<HashMap<u32, Rect>> = OnceCell::new();
+
+/// Key used to look up a texture in `ASSETS`, either by its raw ID or by its registered name
+pub trait AssetKey {
+    /// Get the texture ID in `ASSETS` for this key
+    fn asset_id(&self) -> u32;
+}
+
+impl AssetKey for u32 {
+    fn asset_id(&self) -> u32 {
+        *self
+    }
+}
+
+impl AssetKey for &str {
+    fn asset_id(&self) -> u32 {
+        *ASSET_NAMES.get().expect("ASSET_NAMES not set")
+                    .get(*self).unwrap_or_else(|| panic!("Texture not loaded: {}", self))
+    }
+}
+
+/// Get the `Texture2D` for the given texture ID
+pub fn texture(id: u32) -> Texture2D {
+    *ASSETS.get().expect("ASSETS not set")
+           .get(&id).expect("Texture not set")
+}
+
+/// Get the source rectangle in the atlas texture if the given texture ID refers to an atlas
+/// region
+pub fn source(id: u32) -> Option<Rect> {
+    ASSET_REGIONS.get().and_then(|regions| regions.get(&id).copied())
+}
+
+/// Get the size of the given texture ID. For atlas regions this is the size of the region rather
+/// than the entire atlas.
+pub fn size(id: u32) -> Vec2 {
+    match source(id) {
+        Some(region) => vec2(region.w, region.h),
+        None => {
+            let texture = texture(id);
+            vec2(texture.width(), texture.height())
+        }
+    }
+}
+
+/// Loads textures under string names and populates the global asset maps once everything is
+/// loaded
+///
+/// ```
+/// let mut loader = AssetLoader::new();
+/// loader.load_texture("cards/ace_spades", "assets/cards/ace_spades.png").await;
+/// loader.finalize();
+///
+/// let ace = Piece::new("cards/ace_spades");
+/// ```
+#[derive(Debug, Default)]
+pub struct AssetLoader {
+    /// Textures loaded so far by texture ID
+    textures: HashMap<u32, Texture2D>,
+
+    /// Texture ID for each loaded texture name
+    names: HashMap<String, u32>,
+
+    /// Source rectangle for each texture ID that is a region of an atlas texture
+    regions: HashMap<u32, Rect>,
+}
+
+impl AssetLoader {
+    /// Initialize an `AssetLoader` with no textures
+    pub fn new() -> Self {
+        AssetLoader::default()
+    }
+
+    /// Load the texture at `path` under the given `name`, returning its texture ID
+    pub async fn load_texture(&mut self, name: &str, path: &str) -> u32 {
+        let texture = load_texture(path).await;
+        self.insert(name, texture)
+    }
+
+    /// Add an already created `texture` under the given `name`, returning its texture ID.
+    ///
+    /// Adding a texture under an existing name replaces the previous texture.
+    pub fn insert(&mut self, name: &str, texture: Texture2D) -> u32 {
+        let next_id = self.textures.len() as u32;
+        let id = *self.names.entry(name.to_string()).or_insert(next_id);
+        self.textures.insert(id, texture);
+        self.regions.remove(&id);
+        id
+    }
+
+    /// Load the atlas texture at `path` once and register each of the named `regions` of it as
+    /// its own texture
+    pub async fn load_atlas(&mut self, path: &str, regions: &[(&str, Rect)]) {
+        let atlas = load_texture(path).await;
+        self.insert_atlas(atlas, regions);
+    }
+
+    /// Register each of the named `regions` of the already created `atlas` texture as its own
+    /// texture
+    pub fn insert_atlas(&mut self, atlas: Texture2D, regions: &[(&str, Rect)]) {
+        for (name, region) in regions {
+            let id = self.insert(name, atlas);
+            self.regions.insert(id, *region);
+        }
+    }
+
+    /// Populate the global `ASSETS`, `ASSET_NAMES`, and `ASSET_REGIONS` with the loaded textures
+    pub fn finalize(self) {
+        ASSETS.set(self.textures).expect("ASSETS already set");
+        ASSET_NAMES.set(self.names).expect("ASSET_NAMES already set");
+        ASSET_REGIONS.set(self.regions).expect("ASSET_REGIONS already set");
+    }
+}
@@ -0,0 +1,129 @@
+use macroquad::*;
+use crate::{Layout, Resizeable};
+
+/// Background colors available for generated avatars
+const PALETTE: [(f32, f32, f32); 8] = [
+    (0.90, 0.30, 0.24),
+    (0.90, 0.49, 0.13),
+    (0.95, 0.77, 0.06),
+    (0.18, 0.80, 0.44),
+    (0.10, 0.74, 0.61),
+    (0.20, 0.60, 0.86),
+    (0.61, 0.35, 0.71),
+    (0.20, 0.29, 0.37),
+];
+
+/// Decorative pattern drawn on top of the avatar background
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pattern {
+    /// No decoration
+    Plain,
+
+    /// Ring just inside the edge of the avatar
+    Ring,
+
+    /// Small dots evenly spaced around the edge of the avatar
+    Dots,
+}
+
+/// Procedurally generated avatar showing a player's initials on a colored circle. Used when a
+/// player doesn't provide an avatar image.
+///
+/// The color and pattern are derived from the player's name so the same name always generates
+/// the same avatar.
+#[derive(Debug, Clone)]
+pub struct Avatar {
+    /// Up to two initials drawn in the center of the avatar
+    initials: String,
+
+    /// Background color of the avatar
+    color: Color,
+
+    /// Pattern drawn over the background
+    pattern: Pattern,
+
+    /// Raw diameter of the avatar without resize adjustment
+    size: f32,
+}
+
+impl Avatar {
+    /// Generate an avatar for the player `name` with the raw diameter `size`
+    pub fn new(name: &str, size: f32) -> Self {
+        // Take the first letter of the first two words of the name
+        let initials: String = name.split_whitespace()
+                                   .filter_map(|word| word.chars().next())
+                                   .take(2)
+                                   .flat_map(|c| c.to_uppercase())
+                                   .collect();
+
+        // FNV-1a hash of the name used to pick the color and pattern
+        let mut hash: u32 = 0x811c_9dc5;
+        for byte in name.bytes() {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+
+        let (r, g, b) = PALETTE[hash as usize % PALETTE.len()];
+
+        let pattern = match (hash >> 8) % 3 {
+            0 => Pattern::Plain,
+            1 => Pattern::Ring,
+            _ => Pattern::Dots,
+        };
+
+        Avatar {
+            initials,
+            color: Color::new(r, g, b, 1.0),
+            pattern,
+            size
+        }
+    }
+
+    /// Get the raw width of this `Avatar`
+    pub fn width(&self) -> f32 {
+        self.size
+    }
+
+    /// Get the raw height of this `Avatar`
+    pub fn height(&self) -> f32 {
+        self.size
+    }
+}
+
+impl Resizeable for Avatar {
+    fn draw(&self, location: Vec2, adjustment: f32) {
+        let radius = self.size * adjustment / 2.0;
+        let center_x = location.x() + radius;
+        let center_y = location.y() + radius;
+
+        draw_circle(center_x, center_y, radius, self.color);
+
+        let decoration = Color::new(1.0, 1.0, 1.0, 0.35);
+        match self.pattern {
+            Pattern::Plain => {}
+            Pattern::Ring => {
+                draw_circle_lines(center_x, center_y, radius * 0.85, radius * 0.06, decoration);
+            }
+            Pattern::Dots => {
+                for i in 0..12 {
+                    let angle = i as f32 / 12.0 * std::f32::consts::PI * 2.0;
+                    draw_circle(center_x + angle.cos() * radius * 0.85,
+                                center_y + angle.sin() * radius * 0.85,
+                                radius * 0.05, decoration);
+                }
+            }
+        }
+
+        // Center the initials in the avatar. Text is drawn from its baseline.
+        let font_size = radius;
+        let (text_width, text_height) = measure_text(&self.initials, None, font_size as u16, 1.0);
+        draw_text(&self.initials, center_x - text_width / 2.0, center_y + text_height / 2.0,
+                  font_size, WHITE);
+    }
+}
+
+impl Layout for Avatar {
+    fn raw_size(&self) -> Vec2 {
+        vec2(self.width(), self.height())
+    }
+}
@@ -0,0 +1,117 @@
+use macroquad::*;
+use crate::{Layout, Resizeable};
+
+/// Number of bands used to approximate a gradient when drawing
+const GRADIENT_STEPS: usize = 32;
+
+/// Direction a linear gradient travels from its first color to its second color
+#[derive(Debug, Clone, Copy)]
+pub enum GradientDirection {
+    /// Left edge is the first color, right edge is the second color
+    Horizontal,
+
+    /// Top edge is the first color, bottom edge is the second color
+    Vertical,
+}
+
+/// Procedural fill used in place of a texture for headers, zone shading, and dialog backgrounds
+#[derive(Debug, Clone, Copy)]
+pub enum Fill {
+    /// Fill the entire area with a single color
+    Solid(Color),
+
+    /// Linear gradient from the first color to the second color in the given direction
+    Linear(Color, Color, GradientDirection),
+
+    /// Radial gradient from the first color at the center to the second color at the edges
+    Radial(Color, Color),
+}
+
+impl Fill {
+    /// Draw this fill over the rectangle at (`x`, `y`) with the given `width` and `height`
+    pub fn draw(&self, x: f32, y: f32, width: f32, height: f32) {
+        match *self {
+            Fill::Solid(color) => draw_rectangle(x, y, width, height, color),
+            Fill::Linear(start, end, direction) => {
+                for step in 0..GRADIENT_STEPS {
+                    let color = lerp_color(start, end, step as f32 / (GRADIENT_STEPS - 1) as f32);
+
+                    match direction {
+                        GradientDirection::Horizontal => {
+                            let band = width / GRADIENT_STEPS as f32;
+                            draw_rectangle(x + band * step as f32, y, band, height, color);
+                        }
+                        GradientDirection::Vertical => {
+                            let band = height / GRADIENT_STEPS as f32;
+                            draw_rectangle(x, y + band * step as f32, width, band, color);
+                        }
+                    }
+                }
+            }
+            Fill::Radial(center, edge) => {
+                // Corners outside of the inscribed circle are the edge color
+                draw_rectangle(x, y, width, height, edge);
+
+                // Draw concentric circles from the edge inward so the center is drawn last
+                let max_radius = width.min(height) / 2.0;
+                for step in (0..GRADIENT_STEPS).rev() {
+                    let percent = step as f32 / (GRADIENT_STEPS - 1) as f32;
+                    let color = lerp_color(center, edge, percent);
+                    draw_circle(x + width / 2.0, y + height / 2.0, max_radius * percent.max(0.01),
+                                color);
+                }
+            }
+        }
+    }
+}
+
+/// Linearly interpolate between two colors by `percent` in `[0.0, 1.0]`
+pub(crate) fn lerp_color(start: Color, end: Color, percent: f32) -> Color {
+    Color::new(start.r + (end.r - start.r) * percent,
+               start.g + (end.g - start.g) * percent,
+               start.b + (end.b - start.b) * percent,
+               start.a + (end.a - start.a) * percent)
+}
+
+/// Procedural background element with raw dimensions that is resized like any other element
+#[derive(Debug, Clone)]
+pub struct Background {
+    /// Fill used to draw this background
+    fill: Fill,
+
+    /// Raw width of the background without resize adjustment
+    width: f32,
+
+    /// Raw height of the background without resize adjustment
+    height: f32,
+}
+
+impl Background {
+    /// Create a new `Background` with the given `fill` and raw dimensions
+    pub fn new(fill: Fill, width: f32, height: f32) -> Self {
+        Background { fill, width, height }
+    }
+
+    /// Get the raw width of this `Background`
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Get the raw height of this `Background`
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+}
+
+impl Resizeable for Background {
+    fn draw(&self, location: Vec2, adjustment: f32) {
+        self.fill.draw(location.x(), location.y(), self.width * adjustment,
+                       self.height * adjustment);
+    }
+}
+
+impl Layout for Background {
+    fn raw_size(&self) -> Vec2 {
+        vec2(self.width(), self.height())
+    }
+}
@@ -0,0 +1,144 @@
+use macroquad::*;
+use crate::assets::{self, AssetKey};
+
+/// Rectangular board of `rows` x `cols` equally sized cells on top of a background texture that
+/// is resized to fill the width of the screen, like a `Row`.
+#[derive(Debug, Clone)]
+pub struct Board {
+    /// Number of rows of cells
+    rows: usize,
+
+    /// Number of columns of cells
+    cols: usize,
+
+    /// Texture drawn behind every cell. The raw size of this texture is the raw size of the
+    /// board.
+    background: u32,
+
+    /// Optional texture drawn over each cell, stored row by row
+    cell_textures: Vec<Option<u32>>,
+
+    /// Screen location of the top left of the board
+    location: Vec2,
+}
+
+impl Board {
+    /// Create a `rows` x `cols` board on top of the `background` texture
+    pub fn new<K: AssetKey>(rows: usize, cols: usize, background: K) -> Self {
+        Board {
+            rows,
+            cols,
+            background: background.asset_id(),
+            cell_textures: vec![None; rows * cols],
+            location: Vec2::zero()
+        }
+    }
+
+    /// Get the number of rows of cells
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the number of columns of cells
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Set the screen location of the top left of the board
+    pub fn set_location(&mut self, location: Vec2) {
+        self.location = location;
+    }
+
+    /// Set the texture drawn over the cell at (`row`, `col`)
+    pub fn set_cell_texture<K: AssetKey>(&mut self, row: usize, col: usize, texture: K) {
+        let index = self.index(row, col);
+        self.cell_textures[index] = Some(texture.asset_id());
+    }
+
+    /// Remove the texture drawn over the cell at (`row`, `col`)
+    pub fn clear_cell_texture(&mut self, row: usize, col: usize) {
+        let index = self.index(row, col);
+        self.cell_textures[index] = None;
+    }
+
+    /// Get the index into `cell_textures` for the cell at (`row`, `col`)
+    fn index(&self, row: usize, col: usize) -> usize {
+        assert!(row < self.rows && col < self.cols, "Cell ({}, {}) out of bounds", row, col);
+        row * self.cols + col
+    }
+
+    /// Get the current resize adjustment used to stretch the board across the screen
+    pub fn adjustment(&self) -> f32 {
+        screen_width() / assets::size(self.background).x()
+    }
+
+    /// Get the current adjusted size of a single cell
+    pub fn cell_size(&self) -> Vec2 {
+        let size = assets::size(self.background) * self.adjustment();
+        vec2(size.x() / self.cols as f32, size.y() / self.rows as f32)
+    }
+
+    /// Get the current adjusted width of the `Board`
+    pub fn width(&self) -> f32 {
+        assets::size(self.background).x() * self.adjustment()
+    }
+
+    /// Get the current adjusted height of the `Board`
+    pub fn height(&self) -> f32 {
+        assets::size(self.background).y() * self.adjustment()
+    }
+
+    /// Get the screen location of the top left of the cell at (`row`, `col`)
+    pub fn cell_to_screen(&self, row: usize, col: usize) -> Vec2 {
+        let cell_size = self.cell_size();
+        self.location + vec2(cell_size.x() * col as f32, cell_size.y() * row as f32)
+    }
+
+    /// Get the (row, column) of the cell under the screen position `point`
+    pub fn screen_to_cell(&self, point: Vec2) -> Option<(usize, usize)> {
+        let cell_size = self.cell_size();
+        let offset = point - self.location;
+        if offset.x() < 0.0 || offset.y() < 0.0 {
+            return None;
+        }
+
+        let col = (offset.x() / cell_size.x()) as usize;
+        let row = (offset.y() / cell_size.y()) as usize;
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        Some((row, col))
+    }
+
+    pub fn draw(&self) {
+        // Draw the background resized to the entire board
+        let params = DrawTextureParams {
+            dest_size: Some(vec2(self.width(), self.height())),
+            source: assets::source(self.background),
+            ..Default::default()
+        };
+        draw_texture_ex(assets::texture(self.background), self.location.x(), self.location.y(),
+                        WHITE, params);
+
+        // Draw each cell texture stretched to fill its cell
+        let cell_size = self.cell_size();
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let texture = match self.cell_textures[row * self.cols + col] {
+                    Some(texture) => texture,
+                    None => continue
+                };
+
+                let location = self.cell_to_screen(row, col);
+                let params = DrawTextureParams {
+                    dest_size: Some(cell_size),
+                    source: assets::source(texture),
+                    ..Default::default()
+                };
+                draw_texture_ex(assets::texture(texture), location.x(), location.y(), WHITE,
+                                params);
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use macroquad::*;
+use crate::{Layout, Resizeable};
+use crate::piece::Piece;
+use crate::scale::{Scale, ScaleMode};
+
+#[derive(Debug, Clone)]
+/// Collections of items that will be displayed in the same Column on screen that is ready to be
+/// resized based on the current screen size.
+pub struct Column {
+    /// Current items in the Column
+    items: Vec<Piece>,
+
+    /// Raw width of all items currently in the `Column` without resize adjustment.
+    ///
+    /// To calculate the resize adjustment, `screen_width()` / `raw_width` is calculated and then
+    /// used each draw frame to account for the resize
+    raw_width: f32,
+
+    /// Raw height of all items currently in the `Column` without resize adjustment.
+    ///
+    /// To calculate the resize adjustment, `screen_height()` / `raw_height` is calculated and then
+    /// used each draw frame to account for the resize
+    raw_height: f32,
+
+    /// Number of pixels to put between each element for even vertical spacing
+    spacing: f32,
+
+    /// How the resize adjustment is calculated. Defaults to filling the height of the screen.
+    scale: Scale,
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Column {
+            items: Vec::new(),
+            raw_width: 0.0,
+            raw_height: 0.0,
+            spacing: 0.0,
+            scale: Scale::new(ScaleMode::FitHeight)
+        }
+    }
+}
+
+impl Column {
+    /// Initialize Column to `0` spacing with no items
+    pub fn new() -> Self {
+        Column::default()
+    }
+
+    /// Set the new spacing and recalculating the raw dimensions using the new spacing
+    pub fn spacing(&mut self, spacing: f32) {
+        // Calculate the raw height of only the current items
+        let items_height: f32 = self.items.iter().map(|x| x.height()).sum();
+
+        // Re-calculate raw height with new spacing. Spacing on the top and bottom borders
+        self.raw_height = spacing * (self.items.len() + 1) as f32 + items_height;
+
+        // Re-calculate raw width with new spacing. Spacing on the left and right borders
+        self.raw_width = spacing * 2.0;
+
+        // Update raw width with found max width of the column
+        let mut max_width = 0.0;
+        for item in self.items.iter() {
+            if item.width() > max_width {
+                max_width = item.width();
+            }
+        }
+        self.raw_width += max_width;
+
+        // Set the new spacing
+        self.spacing = spacing;
+    }
+
+    /// Set how the resize adjustment is calculated
+    pub fn scale_mode(&mut self, mode: ScaleMode) {
+        self.scale.mode = mode;
+    }
+
+    /// Cap the resize adjustment at `max` so the items don't grow past that scale on large
+    /// screens
+    pub fn max_scale(&mut self, max: f32) {
+        self.scale.max = Some(max);
+    }
+
+    /// Add an element to the current `Column` and update the raw dimensions based on the new
+    /// element
+    pub fn add(&mut self, item: Piece) {
+        self.raw_height += item.height() + self.spacing;
+        if (item.width() + self.spacing * 2.0) > self.raw_width {
+            self.raw_width = item.width() + self.spacing * 2.0;
+        }
+
+        self.items.push(item);
+    }
+
+    /// Get the current adjusted width of the `Column`
+    pub fn width(&self) -> f32 {
+        self.raw_width * self.adjustment()
+    }
+
+    /// Get the current resize adjustment used to stretch the `Column` down the screen
+    fn adjustment(&self) -> f32 {
+        self.scale.adjustment(self.raw_width, self.raw_height)
+    }
+
+    pub fn draw(&self, location: Vec2) {
+        Resizeable::draw(self, location, self.adjustment());
+    }
+}
+
+impl Resizeable for Column {
+    fn draw(&self, location: Vec2, adjustment: f32) {
+        // Initialize the current X position
+        let curr_x = location.x() + self.spacing * adjustment;
+
+        // Initialize the current Y position from the given starting Y position
+        let mut curr_y = location.y() + self.spacing * adjustment;
+
+        // Draw each item in the column with the found adjustment
+        for item in &self.items {
+            item.draw(vec2(curr_x, curr_y), adjustment);
+
+            // Update Y position for the current item
+            curr_y += item.height() * adjustment + self.spacing * adjustment;
+        }
+    }
+}
+
+impl Layout for Column {
+    fn raw_size(&self) -> Vec2 {
+        vec2(self.raw_width, self.raw_height)
+    }
+}
@@ -0,0 +1,90 @@
+use macroquad::*;
+use std::collections::HashMap;
+use crate::assets::{self, AssetKey};
+
+/// Contextual cursor kinds that can be swapped depending on what the player is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorKind {
+    /// Normal pointer
+    Default,
+
+    /// Grab hand while dragging a piece
+    Grab,
+
+    /// Crosshair while targeting
+    Crosshair,
+
+    /// Hourglass while waiting on another player or the computer
+    Busy,
+}
+
+/// Software cursor that draws a texture from the asset registry at the mouse position.
+///
+/// Kinds without a registered texture fall back to the system cursor.
+#[derive(Debug, Clone)]
+pub struct Cursor {
+    /// Texture ID and hotspot for each registered `CursorKind`. The hotspot is the pixel offset
+    /// into the texture that lines up with the mouse position.
+    textures: HashMap<CursorKind, (u32, Vec2)>,
+
+    /// Currently active cursor kind
+    current: CursorKind,
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Cursor {
+            textures: HashMap::new(),
+            current: CursorKind::Default
+        }
+    }
+}
+
+impl Cursor {
+    /// Initialize the `Cursor` using the system cursor for every kind
+    pub fn new() -> Self {
+        Cursor::default()
+    }
+
+    /// Register the texture to draw for the given `kind` with the `hotspot` pixel in the texture
+    /// that lines up with the mouse position
+    pub fn register<K: AssetKey>(&mut self, kind: CursorKind, texture: K, hotspot: Vec2) {
+        self.textures.insert(kind, (texture.asset_id(), hotspot));
+    }
+
+    /// Set the currently active cursor kind
+    pub fn set(&mut self, kind: CursorKind) {
+        self.current = kind;
+    }
+
+    /// Get the currently active cursor kind
+    pub fn current(&self) -> CursorKind {
+        self.current
+    }
+
+    /// Draw the current cursor at the mouse position. Should be called last each frame so the
+    /// cursor is on top of everything else.
+    pub fn draw(&self) {
+        let (texture, hotspot) = match self.textures.get(&self.current) {
+            Some(entry) => *entry,
+            None => {
+                show_mouse(true);
+                return;
+            }
+        };
+
+        // Hide the system cursor since the software cursor replaces it
+        show_mouse(false);
+
+        // Only draw the atlas region if the cursor texture has one
+        let params = DrawTextureParams {
+            dest_size: Some(assets::size(texture)),
+            source: assets::source(texture),
+            ..Default::default()
+        };
+
+        let (mouse_x, mouse_y) = mouse_position();
+        draw_texture_ex(assets::texture(texture), mouse_x - hotspot.x(), mouse_y - hotspot.y(),
+                        WHITE, params);
+    }
+}
@@ -0,0 +1,117 @@
+use macroquad::*;
+use crate::Resizeable;
+use crate::piece::Piece;
+use crate::row::Row;
+use crate::grid::Grid;
+
+/// Piece currently held by a `DragState`
+#[derive(Debug, Clone)]
+struct Held {
+    /// Piece being dragged
+    piece: Piece,
+
+    /// Adjustment the piece was drawn with when it was picked up
+    adjustment: f32,
+
+    /// Offset from the top left of the piece to the cursor when the piece was picked up, so the
+    /// piece doesn't jump to the cursor
+    grab_offset: Vec2,
+}
+
+/// Tracks a `Piece` picked up with the mouse, drawing it under the cursor until it is released
+#[derive(Debug, Clone, Default)]
+pub struct DragState {
+    /// Piece currently being dragged, if any
+    held: Option<Held>,
+}
+
+/// Piece released by a `DragState` along with the point it was released at. Used to find what
+/// the piece was dropped on.
+#[derive(Debug, Clone)]
+pub struct Dropped {
+    /// Piece that was dropped
+    pub piece: Piece,
+
+    /// Screen position of the cursor when the piece was released
+    pub point: Vec2,
+}
+
+impl DragState {
+    /// Initialize a `DragState` that isn't dragging anything
+    pub fn new() -> Self {
+        DragState::default()
+    }
+
+    /// Pick up `piece` currently drawn at `location` resized using `adjustment`
+    pub fn pick_up(&mut self, piece: Piece, location: Vec2, adjustment: f32) {
+        let (mouse_x, mouse_y) = mouse_position();
+
+        self.held = Some(Held {
+            piece,
+            adjustment,
+            grab_offset: vec2(mouse_x, mouse_y) - location
+        });
+    }
+
+    /// Returns `true` if a piece is currently being dragged
+    pub fn is_dragging(&self) -> bool {
+        self.held.is_some()
+    }
+
+    /// Get the piece currently being dragged
+    pub fn piece(&self) -> Option<&Piece> {
+        self.held.as_ref().map(|held| &held.piece)
+    }
+
+    /// Get the current top left location of the piece being dragged
+    pub fn location(&self) -> Option<Vec2> {
+        let (mouse_x, mouse_y) = mouse_position();
+        self.held.as_ref().map(|held| vec2(mouse_x, mouse_y) - held.grab_offset)
+    }
+
+    /// Check for the mouse button being released, returning the dropped piece on the frame it is
+    /// released
+    pub fn update(&mut self) -> Option<Dropped> {
+        if self.held.is_none() || is_mouse_button_down(MouseButton::Left) {
+            return None;
+        }
+
+        let (mouse_x, mouse_y) = mouse_position();
+        self.held.take().map(|held| Dropped {
+            piece: held.piece,
+            point: vec2(mouse_x, mouse_y)
+        })
+    }
+
+    /// Cancel the current drag, returning the piece that was being dragged
+    pub fn cancel(&mut self) -> Option<Piece> {
+        self.held.take().map(|held| held.piece)
+    }
+
+    /// Draw the dragged piece under the cursor. Should be called after everything else so the
+    /// piece is drawn on top.
+    pub fn draw(&self) {
+        if let (Some(held), Some(location)) = (&self.held, self.location()) {
+            held.piece.draw(location, held.adjustment);
+        }
+    }
+}
+
+impl Dropped {
+    /// Returns `true` if the piece was dropped on `target` drawn at `location` resized using
+    /// `adjustment`
+    pub fn on_piece(&self, target: &Piece, location: Vec2, adjustment: f32) -> bool {
+        target.contains(self.point, location, adjustment)
+    }
+
+    /// Get the index of the item in `row` drawn at `location` that the piece was dropped on
+    pub fn on_row(&self, row: &Row, location: Vec2) -> Option<usize> {
+        row.hit_test(self.point, location)
+    }
+
+    /// Get the (row, column) of the cell in `grid` drawn at `location` that the piece was dropped
+    /// on
+    pub fn on_cell(&self, grid: &Grid, location: Vec2) -> Option<(usize, usize)> {
+        grid.cell_at(self.point, location)
+    }
+}
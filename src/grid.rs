@@ -0,0 +1,216 @@
+use macroquad::*;
+use crate::{Layout, Resizeable};
+use crate::piece::Piece;
+use crate::scale::{Scale, ScaleMode};
+
+#[derive(Debug, Clone)]
+/// Fixed number of rows and columns of cells, each holding an optional `Piece`, that is resized
+/// to fit the current screen size.
+pub struct Grid {
+    /// Number of rows in the grid
+    rows: usize,
+
+    /// Number of columns in the grid
+    cols: usize,
+
+    /// Piece in each cell, stored row by row
+    cells: Vec<Option<Piece>>,
+
+    /// Raw width of a single cell without resize adjustment. This is the width of the widest
+    /// `Piece` in the grid.
+    cell_width: f32,
+
+    /// Raw height of a single cell without resize adjustment. This is the height of the tallest
+    /// `Piece` in the grid.
+    cell_height: f32,
+
+    /// Number of pixels to put between each cell and around the border of the grid
+    spacing: f32,
+
+    /// How the resize adjustment is calculated. Defaults to fitting the entire grid on the
+    /// screen.
+    scale: Scale,
+}
+
+impl Grid {
+    /// Initialize an empty `rows` x `cols` Grid with `0` spacing
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Grid {
+            rows,
+            cols,
+            cells: vec![None; rows * cols],
+            cell_width: 0.0,
+            cell_height: 0.0,
+            spacing: 0.0,
+            scale: Scale::new(ScaleMode::Contain)
+        }
+    }
+
+    /// Get the number of rows in the grid
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the number of columns in the grid
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Set the number of pixels between each cell and around the border of the grid
+    pub fn spacing(&mut self, spacing: f32) {
+        self.spacing = spacing;
+    }
+
+    /// Set how the resize adjustment is calculated
+    pub fn scale_mode(&mut self, mode: ScaleMode) {
+        self.scale.mode = mode;
+    }
+
+    /// Cap the resize adjustment at `max` so the cells don't grow past that scale on large
+    /// screens
+    pub fn max_scale(&mut self, max: f32) {
+        self.scale.max = Some(max);
+    }
+
+    /// Place `piece` in the cell at (`row`, `col`), returning the piece previously in that cell
+    pub fn set(&mut self, row: usize, col: usize, piece: Piece) -> Option<Piece> {
+        let index = self.index(row, col);
+        let old = self.cells[index].replace(piece);
+        self.recalculate();
+        old
+    }
+
+    /// Remove and return the piece in the cell at (`row`, `col`)
+    pub fn take(&mut self, row: usize, col: usize) -> Option<Piece> {
+        let index = self.index(row, col);
+        let old = self.cells[index].take();
+        self.recalculate();
+        old
+    }
+
+    /// Get the piece in the cell at (`row`, `col`)
+    pub fn get(&self, row: usize, col: usize) -> Option<&Piece> {
+        self.cells[self.index(row, col)].as_ref()
+    }
+
+    /// Get the index into `cells` for the cell at (`row`, `col`)
+    fn index(&self, row: usize, col: usize) -> usize {
+        assert!(row < self.rows && col < self.cols, "Cell ({}, {}) out of bounds", row, col);
+        row * self.cols + col
+    }
+
+    /// Recalculate the raw cell size from the largest piece currently in the grid
+    fn recalculate(&mut self) {
+        self.cell_width = 0.0;
+        self.cell_height = 0.0;
+
+        for piece in self.cells.iter().flatten() {
+            if piece.width() > self.cell_width {
+                self.cell_width = piece.width();
+            }
+
+            if piece.height() > self.cell_height {
+                self.cell_height = piece.height();
+            }
+        }
+    }
+
+    /// Raw width of the entire grid without resize adjustment
+    fn raw_width(&self) -> f32 {
+        self.cell_width * self.cols as f32 + self.spacing * (self.cols + 1) as f32
+    }
+
+    /// Raw height of the entire grid without resize adjustment
+    fn raw_height(&self) -> f32 {
+        self.cell_height * self.rows as f32 + self.spacing * (self.rows + 1) as f32
+    }
+
+    /// Get the adjustment used to resize the grid based on its scale mode while keeping cells in
+    /// proportion
+    pub fn adjustment(&self) -> f32 {
+        self.scale.adjustment(self.raw_width(), self.raw_height())
+    }
+
+    /// Get the current adjusted width of the `Grid`
+    pub fn width(&self) -> f32 {
+        self.raw_width() * self.adjustment()
+    }
+
+    /// Get the current adjusted height of the `Grid`
+    pub fn height(&self) -> f32 {
+        self.raw_height() * self.adjustment()
+    }
+
+    /// Get the (row, column) of the cell under `point` when the `Grid` is drawn at `location`.
+    /// Points in the spacing between cells are not in any cell.
+    pub fn cell_at(&self, point: Vec2, location: Vec2) -> Option<(usize, usize)> {
+        // An empty grid has no cells to land on
+        if self.cell_width <= 0.0 || self.cell_height <= 0.0 {
+            return None;
+        }
+
+        let adjustment = self.adjustment();
+
+        let cell_width  = self.cell_width  * adjustment;
+        let cell_height = self.cell_height * adjustment;
+        let spacing     = self.spacing     * adjustment;
+
+        // Position of the point relative to the top left of the first cell
+        let x = point.x() - location.x() - spacing;
+        let y = point.y() - location.y() - spacing;
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let col = (x / (cell_width  + spacing)) as usize;
+        let row = (y / (cell_height + spacing)) as usize;
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        // Ignore points in the spacing after the cell
+        if x - (cell_width + spacing) * col as f32 > cell_width ||
+           y - (cell_height + spacing) * row as f32 > cell_height {
+            return None;
+        }
+
+        Some((row, col))
+    }
+
+    pub fn draw(&self, location: Vec2) {
+        Resizeable::draw(self, location, self.adjustment());
+    }
+}
+
+impl Resizeable for Grid {
+    fn draw(&self, location: Vec2, adjustment: f32) {
+        let cell_width  = self.cell_width  * adjustment;
+        let cell_height = self.cell_height * adjustment;
+        let spacing     = self.spacing     * adjustment;
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let piece = match &self.cells[row * self.cols + col] {
+                    Some(piece) => piece,
+                    None => continue
+                };
+
+                // Top left of the current cell
+                let cell_x = location.x() + spacing + (cell_width  + spacing) * col as f32;
+                let cell_y = location.y() + spacing + (cell_height + spacing) * row as f32;
+
+                // Center the piece in the cell
+                let x_offset = (cell_width  - piece.width()  * adjustment) / 2.0;
+                let y_offset = (cell_height - piece.height() * adjustment) / 2.0;
+
+                piece.draw(vec2(cell_x + x_offset, cell_y + y_offset), adjustment);
+            }
+        }
+    }
+}
+
+impl Layout for Grid {
+    fn raw_size(&self) -> Vec2 {
+        vec2(self.raw_width(), self.raw_height())
+    }
+}
@@ -0,0 +1,210 @@
+use macroquad::*;
+use std::collections::HashMap;
+use crate::Resizeable;
+use crate::piece::Piece;
+
+/// Orientation of the hexes in a `HexBoard`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HexOrientation {
+    /// Point at the top and bottom of each hex, with hexes forming horizontal rows
+    PointyTop,
+
+    /// Flat edge at the top and bottom of each hex, with hexes forming vertical columns
+    FlatTop,
+}
+
+/// Board of hexes addressed by axial (`q`, `r`) coordinates, each holding a `Piece` that is
+/// centered in its hex. The board is resized to fill the width of the screen, like a `Row`.
+#[derive(Debug, Clone)]
+pub struct HexBoard {
+    /// Orientation of the hexes
+    orientation: HexOrientation,
+
+    /// Raw width of a single hex without resize adjustment
+    hex_width: f32,
+
+    /// Raw height of a single hex without resize adjustment
+    hex_height: f32,
+
+    /// Piece in each hex by axial (`q`, `r`) coordinate
+    hexes: HashMap<(i32, i32), Piece>,
+
+    /// Screen location of the top left of the board
+    location: Vec2,
+}
+
+impl HexBoard {
+    /// Create an empty board with hexes of the given raw size. Hex textures are expected to fill
+    /// this size, so the hexes tile without overlapping.
+    pub fn new(orientation: HexOrientation, hex_width: f32, hex_height: f32) -> Self {
+        HexBoard {
+            orientation,
+            hex_width,
+            hex_height,
+            hexes: HashMap::new(),
+            location: Vec2::zero()
+        }
+    }
+
+    /// Set the screen location of the top left of the board
+    pub fn set_location(&mut self, location: Vec2) {
+        self.location = location;
+    }
+
+    /// Place `piece` in the hex at (`q`, `r`), returning the piece previously in that hex
+    pub fn insert(&mut self, q: i32, r: i32, piece: Piece) -> Option<Piece> {
+        self.hexes.insert((q, r), piece)
+    }
+
+    /// Remove and return the piece in the hex at (`q`, `r`)
+    pub fn remove(&mut self, q: i32, r: i32) -> Option<Piece> {
+        self.hexes.remove(&(q, r))
+    }
+
+    /// Get the piece in the hex at (`q`, `r`)
+    pub fn get(&self, q: i32, r: i32) -> Option<&Piece> {
+        self.hexes.get(&(q, r))
+    }
+
+    /// Get the raw center of the hex at (`q`, `r`) relative to the center of hex (0, 0)
+    fn raw_center(&self, q: i32, r: i32) -> Vec2 {
+        let (q, r) = (q as f32, r as f32);
+
+        // Rows (or columns) of hexes overlap by a quarter of a hex
+        match self.orientation {
+            HexOrientation::PointyTop => {
+                vec2(self.hex_width * (q + r / 2.0), self.hex_height * 0.75 * r)
+            }
+            HexOrientation::FlatTop => {
+                vec2(self.hex_width * 0.75 * q, self.hex_height * (r + q / 2.0))
+            }
+        }
+    }
+
+    /// Get the raw bounding box of every hex on the board relative to the center of hex (0, 0)
+    fn raw_bounds(&self) -> Rect {
+        let mut centers = self.hexes.keys().map(|(q, r)| self.raw_center(*q, *r));
+
+        // An empty board is the size of a single hex
+        let first = match centers.next() {
+            Some(center) => center,
+            None => {
+                return Rect::new(-self.hex_width / 2.0, -self.hex_height / 2.0, self.hex_width,
+                                 self.hex_height);
+            }
+        };
+
+        let (min, max) = centers.fold((first, first), |(min, max), center| {
+            (min.min(center), max.max(center))
+        });
+
+        Rect::new(min.x() - self.hex_width / 2.0, min.y() - self.hex_height / 2.0,
+                  max.x() - min.x() + self.hex_width, max.y() - min.y() + self.hex_height)
+    }
+
+    /// Get the current resize adjustment used to stretch the board across the screen
+    pub fn adjustment(&self) -> f32 {
+        bounds_adjustment(&self.raw_bounds())
+    }
+
+    /// Get the current adjusted width of the `HexBoard`
+    pub fn width(&self) -> f32 {
+        self.raw_bounds().w * self.adjustment()
+    }
+
+    /// Get the current adjusted height of the `HexBoard`
+    pub fn height(&self) -> f32 {
+        self.raw_bounds().h * self.adjustment()
+    }
+
+    /// Get the screen position of the center of the hex at (`q`, `r`)
+    pub fn hex_to_screen(&self, q: i32, r: i32) -> Vec2 {
+        let bounds = self.raw_bounds();
+        self.center_on_screen(&bounds, bounds_adjustment(&bounds), q, r)
+    }
+
+    /// Get the screen position of the center of the hex at (`q`, `r`) given the already
+    /// calculated raw `bounds` of the board and its `adjustment`
+    fn center_on_screen(&self, bounds: &Rect, adjustment: f32, q: i32, r: i32) -> Vec2 {
+        let center = self.raw_center(q, r) - vec2(bounds.x, bounds.y);
+        self.location + center * adjustment
+    }
+
+    /// Get the axial (`q`, `r`) coordinate of the hex on the board under the screen position
+    /// `point`
+    pub fn screen_to_hex(&self, point: Vec2) -> Option<(i32, i32)> {
+        let bounds = self.raw_bounds();
+        let adjustment = bounds_adjustment(&bounds);
+
+        // Convert the point to a raw position relative to the center of hex (0, 0)
+        let raw = (point - self.location) / adjustment + vec2(bounds.x, bounds.y);
+
+        // Invert `raw_center` to get fractional axial coordinates
+        let (q, r) = match self.orientation {
+            HexOrientation::PointyTop => {
+                let r = raw.y() / (self.hex_height * 0.75);
+                (raw.x() / self.hex_width - r / 2.0, r)
+            }
+            HexOrientation::FlatTop => {
+                let q = raw.x() / (self.hex_width * 0.75);
+                (q, raw.y() / self.hex_height - q / 2.0)
+            }
+        };
+
+        let hex = round_axial(q, r);
+        if self.hexes.contains_key(&hex) {
+            Some(hex)
+        } else {
+            None
+        }
+    }
+
+    pub fn draw(&self) {
+        // Measure the board once rather than for every hex
+        let bounds = self.raw_bounds();
+        let adjustment = bounds_adjustment(&bounds);
+
+        // Draw from top to bottom, left to right so any overlap between hex textures is
+        // consistent between frames
+        let mut hexes: Vec<(&(i32, i32), &Piece)> = self.hexes.iter().collect();
+        hexes.sort_by(|(a, _), (b, _)| {
+            let a = self.raw_center(a.0, a.1);
+            let b = self.raw_center(b.0, b.1);
+            (a.y(), a.x()).partial_cmp(&(b.y(), b.x())).unwrap()
+        });
+
+        for ((q, r), piece) in hexes {
+            // Center the piece in its hex
+            let center = self.center_on_screen(&bounds, adjustment, *q, *r);
+            let location = center - vec2(piece.width(), piece.height()) * adjustment / 2.0;
+            piece.draw(location, adjustment);
+        }
+    }
+}
+
+/// Get the resize adjustment stretching a board with the raw `bounds` across the screen
+fn bounds_adjustment(bounds: &Rect) -> f32 {
+    screen_width() / bounds.w
+}
+
+/// Round fractional axial coordinates to the nearest hex using cube coordinates
+fn round_axial(q: f32, r: f32) -> (i32, i32) {
+    let s = -q - r;
+
+    let mut rounded_q = q.round();
+    let mut rounded_r = r.round();
+    let rounded_s = s.round();
+
+    // Fix the coordinate with the largest rounding error so that q + r + s == 0
+    let q_diff = (rounded_q - q).abs();
+    let r_diff = (rounded_r - r).abs();
+    let s_diff = (rounded_s - s).abs();
+
+    if q_diff > r_diff && q_diff > s_diff {
+        rounded_q = -rounded_r - rounded_s;
+    } else if r_diff > s_diff {
+        rounded_r = -rounded_q - rounded_s;
+    }
+
+    (rounded_q as i32, rounded_r as i32)
+}
@@ -0,0 +1,147 @@
+use macroquad::*;
+use std::collections::{HashMap, HashSet};
+
+/// Raw key or mouse button that triggers a named action
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Binding {
+    /// Keyboard key
+    Key(KeyCode),
+
+    /// Mouse button
+    Mouse(MouseButton),
+}
+
+impl Binding {
+    /// Returns `true` if the key or mouse button is currently held
+    fn is_down(&self) -> bool {
+        match *self {
+            Binding::Key(key) => is_key_down(key),
+            Binding::Mouse(button) => is_mouse_button_down(button),
+        }
+    }
+
+    /// Returns `true` if the key or mouse button was pressed this frame
+    fn is_pressed(&self) -> bool {
+        match *self {
+            Binding::Key(key) => is_key_pressed(key),
+            Binding::Mouse(button) => is_mouse_button_pressed(button),
+        }
+    }
+
+    /// Returns `true` if the key or mouse button was released this frame
+    fn is_released(&self) -> bool {
+        match *self {
+            Binding::Key(key) => is_key_released(key),
+            Binding::Mouse(button) => is_mouse_button_released(button),
+        }
+    }
+}
+
+/// State of every bound action for a single frame
+#[derive(Debug, Default, Clone)]
+pub struct Actions {
+    /// Actions currently held
+    down: HashSet<String>,
+
+    /// Actions that started being held this frame
+    pressed: HashSet<String>,
+
+    /// Actions that stopped being held this frame
+    released: HashSet<String>,
+}
+
+impl Actions {
+    /// Returns `true` if `action` is currently held
+    pub fn is_down(&self, action: &str) -> bool {
+        self.down.contains(action)
+    }
+
+    /// Returns `true` if `action` started being held this frame
+    pub fn is_pressed(&self, action: &str) -> bool {
+        self.pressed.contains(action)
+    }
+
+    /// Returns `true` if `action` stopped being held this frame
+    pub fn is_released(&self, action: &str) -> bool {
+        self.released.contains(action)
+    }
+}
+
+/// Maps keys and mouse buttons to named game actions, such as "confirm", "cancel", or "rotate
+/// piece", so game loops don't hardcode keys and players can rebind them
+///
+/// ```
+/// let mut input = InputMap::new();
+/// input.bind("confirm", Binding::Key(KeyCode::Enter));
+/// input.bind("confirm", Binding::Mouse(MouseButton::Left));
+/// input.bind("rotate piece", Binding::Key(KeyCode::R));
+///
+/// // Each frame
+/// let actions = input.update();
+/// if actions.is_pressed("rotate piece") {
+///     piece.set_rotation(piece.rotation() + std::f32::consts::FRAC_PI_2);
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InputMap {
+    /// Bindings of each action
+    bindings: HashMap<String, Vec<Binding>>,
+}
+
+impl InputMap {
+    /// Initialize an `InputMap` with no bindings
+    pub fn new() -> Self {
+        InputMap::default()
+    }
+
+    /// Add `binding` as a trigger for `action`. An action can have any number of bindings.
+    pub fn bind(&mut self, action: &str, binding: Binding) {
+        let bindings = self.bindings.entry(action.to_string()).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Remove `binding` as a trigger for `action`
+    pub fn unbind(&mut self, action: &str, binding: Binding) {
+        if let Some(bindings) = self.bindings.get_mut(action) {
+            bindings.retain(|bound| *bound != binding);
+        }
+    }
+
+    /// Replace every binding of `action` with `bindings`, such as from a settings screen
+    pub fn rebind(&mut self, action: &str, bindings: Vec<Binding>) {
+        self.bindings.insert(action.to_string(), bindings);
+    }
+
+    /// Get the bindings of `action`
+    pub fn bindings(&self, action: &str) -> &[Binding] {
+        match self.bindings.get(action) {
+            Some(bindings) => bindings,
+            None => &[]
+        }
+    }
+
+    /// Read the current keyboard and mouse state and return the state of every action for this
+    /// frame. Presses and releases come from macroquad's events, so a tap or click that goes
+    /// down and up within a single frame is still reported as both pressed and released.
+    pub fn update(&self) -> Actions {
+        let mut actions = Actions::default();
+
+        for (action, bindings) in self.bindings.iter() {
+            if bindings.iter().any(|binding| binding.is_down()) {
+                actions.down.insert(action.clone());
+            }
+
+            if bindings.iter().any(|binding| binding.is_pressed()) {
+                actions.pressed.insert(action.clone());
+            }
+
+            if bindings.iter().any(|binding| binding.is_released()) {
+                actions.released.insert(action.clone());
+            }
+        }
+
+        actions
+    }
+}
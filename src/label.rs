@@ -0,0 +1,70 @@
+use macroquad::*;
+use crate::Resizeable;
+
+/// Text element, such as a score, player name, or card value, that is resized with the same
+/// adjustment as pieces. Use `Piece::from_label` to add a `Label` to a `Row` or as a `Piece` child.
+#[derive(Debug, Clone)]
+pub struct Label {
+    /// Text to draw
+    text: String,
+
+    /// Raw font size without resize adjustment
+    font_size: f32,
+
+    /// Color of the text
+    color: Color,
+}
+
+impl Label {
+    /// Create a new `Label` drawing `text` at the raw `font_size` in `color`
+    pub fn new(text: &str, font_size: f32, color: Color) -> Self {
+        Label {
+            text: text.to_string(),
+            font_size,
+            color
+        }
+    }
+
+    /// Replace the text of the `Label`
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Get the text of the `Label`
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Set the color of the text
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    /// Get the raw size of the text without resize adjustment
+    pub fn size(&self) -> Vec2 {
+        let (width, height) = measure_text(&self.text, None, self.font_size as u16, 1.0);
+        vec2(width, height)
+    }
+
+    /// Draw the text with its top left at `location` resized using `adjustment`, with the text
+    /// color multiplied by `tint`
+    pub(crate) fn draw_tinted(&self, location: Vec2, adjustment: f32, tint: Color) {
+        let color = Color::new(self.color.r * tint.r, self.color.g * tint.g,
+                               self.color.b * tint.b, self.color.a * tint.a);
+
+        // Text is drawn from its baseline, so offset by the height of the text
+        let params = TextParams {
+            font_size: (self.font_size * adjustment) as u16,
+            color,
+            ..Default::default()
+        };
+        draw_text_ex(&self.text, location.x(), location.y() + self.size().y() * adjustment,
+                     params);
+    }
+}
+
+impl Resizeable for Label {
+    fn draw(&self, location: Vec2, adjustment: f32) {
+        self.draw_tinted(location, adjustment, WHITE);
+    }
+}
@@ -0,0 +1,85 @@
+use macroquad::*;
+use crate::Resizeable;
+use crate::piece::Piece;
+use crate::background::Fill;
+
+/// Element waiting to be drawn in a `Layer`
+enum Item<'a> {
+    /// Borrowed element drawn resized using the adjustment of the `Drawable`
+    Resizeable(&'a dyn Resizeable),
+
+    /// Procedural fill of the given drawn size, such as the background of a `Row`
+    Fill(Fill, Vec2),
+}
+
+/// Drawable element waiting in a `Layer` along with where to draw it
+struct Drawable<'a> {
+    /// Draw order of the element. Higher values are drawn on top.
+    z_index: i32,
+
+    /// Element to draw
+    item: Item<'a>,
+
+    /// Location to draw the element at
+    location: Vec2,
+
+    /// Resize adjustment to draw the element with
+    adjustment: f32,
+}
+
+/// Collection of drawables gathered each frame and drawn sorted by z-index, so overlapping
+/// elements from different containers render in the right order.
+///
+/// ```
+/// let mut layer = Layer::new();
+/// hand.add_to_layer(&mut layer, vec2(0., 0.));
+/// layer.push_piece(&dragged_card, card_location, adjustment);
+/// layer.draw();
+/// ```
+#[derive(Default)]
+pub struct Layer<'a> {
+    /// Drawables added so far this frame
+    drawables: Vec<Drawable<'a>>,
+}
+
+impl<'a> Layer<'a> {
+    /// Initialize an empty `Layer`
+    pub fn new() -> Self {
+        Layer { drawables: Vec::new() }
+    }
+
+    /// Add `item` to be drawn at `location` resized using `adjustment` with the given `z_index`
+    pub fn push(&mut self, z_index: i32, item: &'a dyn Resizeable, location: Vec2,
+                adjustment: f32) {
+        let item = Item::Resizeable(item);
+        self.drawables.push(Drawable { z_index, item, location, adjustment });
+    }
+
+    /// Add `fill` to be drawn at `location` with the already adjusted `size` and the given
+    /// `z_index`
+    pub fn push_fill(&mut self, z_index: i32, fill: Fill, location: Vec2, size: Vec2) {
+        let item = Item::Fill(fill, size);
+        self.drawables.push(Drawable { z_index, item, location, adjustment: 1.0 });
+    }
+
+    /// Add `piece` to be drawn at `location` resized using `adjustment` using the z-index of the
+    /// piece
+    pub fn push_piece(&mut self, piece: &'a Piece, location: Vec2, adjustment: f32) {
+        self.push(piece.z_index(), piece, location, adjustment);
+    }
+
+    /// Draw everything in the `Layer` from lowest to highest z-index and clear it for the next
+    /// frame. Drawables with the same z-index are drawn in the order they were added.
+    pub fn draw(&mut self) {
+        self.drawables.sort_by_key(|drawable| drawable.z_index);
+
+        for drawable in self.drawables.drain(..) {
+            let location = drawable.location;
+            match drawable.item {
+                Item::Resizeable(item) => item.draw(location, drawable.adjustment),
+                Item::Fill(fill, size) => fill.draw(location.x(), location.y(), size.x(),
+                                                    size.y()),
+            }
+        }
+    }
+}
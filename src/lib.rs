@@ -1,10 +1,36 @@
 use macroquad::*;
 
+pub mod scale;
 pub mod row;
+pub mod column;
+pub mod grid;
+pub mod board;
+pub mod hex;
+pub mod drag;
+pub mod layer;
+pub mod animation;
+pub mod anim_state;
+pub mod label;
 pub mod piece;
+pub mod overlay;
 pub mod assets;
+pub mod background;
+pub mod cursor;
+pub mod toss;
+pub mod avatar;
+pub mod names;
+pub mod scene;
+pub mod input;
 
 pub trait Resizeable {
     /// Draws the element at the given `location` resized using `adjustment`
     fn draw(&self, location: Vec2, adjustment: f32);
 }
+
+/// Element with a raw size that can be nested inside of a layout container, such as a `Column` of
+/// `Piece`s inside of a `Row`. Nested elements are drawn using the adjustment of the outermost
+/// container so sizes stay consistent all the way down.
+pub trait Layout: Resizeable + std::fmt::Debug {
+    /// Get the raw size of the element without resize adjustment
+    fn raw_size(&self) -> Vec2;
+}
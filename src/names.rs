@@ -0,0 +1,124 @@
+use macroquad::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Characters used for room codes. Ambiguous characters (0/O, 1/I/L) are left out so codes can
+/// be read aloud and typed without mistakes.
+const ROOM_CODE_CHARS: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+
+/// Adjectives used for random player names
+const ADJECTIVES: [&str; 16] = [
+    "Brave", "Clever", "Daring", "Eager", "Fancy", "Gentle", "Happy", "Jolly",
+    "Lucky", "Mighty", "Nimble", "Proud", "Quiet", "Swift", "Witty", "Zesty",
+];
+
+/// Nouns used for random player names
+const NOUNS: [&str; 16] = [
+    "Badger", "Comet", "Dragon", "Falcon", "Gecko", "Heron", "Knight", "Lynx",
+    "Meeple", "Otter", "Pawn", "Raven", "Rook", "Tiger", "Walrus", "Wizard",
+];
+
+/// Consonants used for seed phrase words, each encoding 4 bits
+const CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+
+/// Vowels used for seed phrase words, each encoding 2 bits
+const VOWELS: &[u8; 4] = b"aiou";
+
+/// State of the generator used for room codes, names, and seeds. This is kept separate from
+/// macroquad's `rand`, which is never seeded by default and may be seeded by the game for
+/// deterministic shuffles. `0` means the generator has not been seeded yet.
+static RNG_STATE: AtomicU64 = AtomicU64::new(0);
+
+/// Get the next random `u64` using xorshift64*, seeding from the current time on first use so
+/// each launch generates different values
+fn next_u64() -> u64 {
+    let mut state = RNG_STATE.load(Ordering::Relaxed);
+    if state == 0 {
+        // Seed from the time in microseconds, keeping the state nonzero
+        state = (miniquad::date::now() * 1_000_000.0) as u64 | 1;
+    }
+
+    state ^= state >> 12;
+    state ^= state << 25;
+    state ^= state >> 27;
+    RNG_STATE.store(state, Ordering::Relaxed);
+
+    state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+}
+
+/// Get a random index in `0..len`
+fn random_index(len: usize) -> usize {
+    (next_u64() % len as u64) as usize
+}
+
+/// Generate a random room code of `length` characters, such as `K7QM`
+pub fn room_code(length: usize) -> String {
+    (0..length).map(|_| ROOM_CODE_CHARS[random_index(ROOM_CODE_CHARS.len())] as char)
+               .collect()
+}
+
+/// Generate a random player name, such as `Nimble Otter`
+pub fn player_name() -> String {
+    format!("{} {}", ADJECTIVES[random_index(ADJECTIVES.len())],
+                     NOUNS[random_index(NOUNS.len())])
+}
+
+/// Convert a `u64` seed into a human friendly phrase of four words, such as
+/// `lusab-babad-gutih-tugad`.
+///
+/// Each word encodes 16 bits as consonant-vowel-consonant-vowel-consonant, so the phrase can be
+/// converted back into the exact seed with `phrase_to_seed`.
+pub fn seed_to_phrase(seed: u64) -> String {
+    let mut words = Vec::new();
+
+    // Encode the seed 16 bits at a time, most significant bits first
+    for shift in [48, 32, 16, 0].iter() {
+        let chunk = (seed >> shift) as u16;
+
+        let mut word = String::new();
+        word.push(CONSONANTS[(chunk >> 12) as usize & 0xf] as char);
+        word.push(VOWELS[(chunk >> 10) as usize & 0x3] as char);
+        word.push(CONSONANTS[(chunk >> 6) as usize & 0xf] as char);
+        word.push(VOWELS[(chunk >> 4) as usize & 0x3] as char);
+        word.push(CONSONANTS[chunk as usize & 0xf] as char);
+        words.push(word);
+    }
+
+    words.join("-")
+}
+
+/// Convert a phrase created by `seed_to_phrase` back into its `u64` seed. Returns `None` if the
+/// phrase is not a valid seed phrase.
+pub fn phrase_to_seed(phrase: &str) -> Option<u64> {
+    let words: Vec<&str> = phrase.trim().split('-').collect();
+    if words.len() != 4 {
+        return None;
+    }
+
+    let mut seed = 0u64;
+    for word in words {
+        let letters = word.as_bytes();
+        if letters.len() != 5 {
+            return None;
+        }
+
+        // Find the bits for each letter in its position
+        let consonant = |c: u8| CONSONANTS.iter().position(|&x| x == c.to_ascii_lowercase());
+        let vowel     = |c: u8| VOWELS.iter().position(|&x| x == c.to_ascii_lowercase());
+
+        let chunk = (consonant(letters[0])? << 12)
+                  | (vowel(letters[1])?     << 10)
+                  | (consonant(letters[2])? << 6)
+                  | (vowel(letters[3])?     << 4)
+                  |  consonant(letters[4])?;
+
+        seed = (seed << 16) | chunk as u64;
+    }
+
+    Some(seed)
+}
+
+/// Generate a random seed along with its human friendly phrase
+pub fn random_seed() -> (u64, String) {
+    let seed = next_u64();
+    (seed, seed_to_phrase(seed))
+}
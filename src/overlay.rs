@@ -0,0 +1,313 @@
+use macroquad::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use crate::assets::{self, AssetKey};
+use crate::piece::Piece;
+
+/// Seconds a damage popup stays on screen
+const POPUP_SECONDS: f64 = 1.0;
+
+/// Raw distance a damage popup rises over its lifetime
+const POPUP_RISE: f32 = 24.0;
+
+/// Where an overlay is placed on its piece, using the same relations as `Piece::add_child`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    /// Position relative to the size of the piece's texture
+    pub rel_parent: Vec2,
+
+    /// Offset relative to the size of the overlay itself
+    pub rel_self: Vec2,
+}
+
+impl Anchor {
+    /// Create a new `Anchor` with the given relations to the piece and to the overlay
+    pub fn new(rel_parent: Vec2, rel_self: Vec2) -> Self {
+        Anchor { rel_parent, rel_self }
+    }
+
+    /// Get the top left of an overlay of drawn size `own` on a piece drawn at `location` with
+    /// drawn size `size`
+    fn place(&self, location: Vec2, size: Vec2, own: Vec2) -> Vec2 {
+        vec2(location.x() + size.x() * self.rel_parent.x() + own.x() * self.rel_self.x(),
+             location.y() + size.y() * self.rel_parent.y() + own.y() * self.rel_self.y())
+    }
+}
+
+/// Health of a piece shared between game logic and its overlay. Clones share the same value,
+/// so the overlay updates as soon as the game changes it.
+#[derive(Debug, Clone)]
+pub struct Health {
+    /// Current health
+    current: Rc<Cell<u32>>,
+
+    /// Maximum health
+    max: u32,
+}
+
+impl Health {
+    /// Create a new `Health` starting full at `max`
+    pub fn new(max: u32) -> Self {
+        Health { current: Rc::new(Cell::new(max)), max }
+    }
+
+    /// Get the current health
+    pub fn get(&self) -> u32 {
+        self.current.get()
+    }
+
+    /// Get the maximum health
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Set the current health, capped at the maximum
+    pub fn set(&self, health: u32) {
+        self.current.set(health.min(self.max));
+    }
+
+    /// Remove `amount` health, stopping at `0`
+    pub fn damage(&self, amount: u32) {
+        self.set(self.get().saturating_sub(amount));
+    }
+
+    /// Restore `amount` health, stopping at the maximum
+    pub fn heal(&self, amount: u32) {
+        self.set(self.get().saturating_add(amount));
+    }
+
+    /// Get the fraction of health remaining in `[0.0, 1.0]`
+    pub fn fraction(&self) -> f32 {
+        if self.max == 0 {
+            return 0.0;
+        }
+
+        self.get() as f32 / self.max as f32
+    }
+}
+
+/// How a `Health` overlay is drawn. Sizes are raw and resized with the piece.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HealthStyle {
+    /// Bar that empties from the right
+    Bar {
+        /// Raw width of the full bar
+        width: f32,
+
+        /// Raw height of the bar
+        height: f32,
+    },
+
+    /// One square pip per point of maximum health, filled for each remaining point
+    Pips {
+        /// Raw size of each pip
+        size: f32,
+
+        /// Raw space between pips
+        spacing: f32,
+    },
+}
+
+/// Status effect icons of a piece, such as poisoned or stunned, shared between game logic and
+/// its overlay
+#[derive(Debug, Clone, Default)]
+pub struct StatusIcons {
+    /// Texture ID of each active status, drawn left to right
+    icons: Rc<RefCell<Vec<u32>>>,
+}
+
+impl StatusIcons {
+    /// Initialize with no active statuses
+    pub fn new() -> Self {
+        StatusIcons::default()
+    }
+
+    /// Show the status `icon` if it isn't already shown
+    pub fn add<K: AssetKey>(&self, icon: K) {
+        let id = icon.asset_id();
+        let mut icons = self.icons.borrow_mut();
+        if !icons.contains(&id) {
+            icons.push(id);
+        }
+    }
+
+    /// Stop showing the status `icon`
+    pub fn remove<K: AssetKey>(&self, icon: K) {
+        let id = icon.asset_id();
+        self.icons.borrow_mut().retain(|icon| *icon != id);
+    }
+
+    /// Stop showing every status
+    pub fn clear(&self) {
+        self.icons.borrow_mut().clear();
+    }
+}
+
+/// Damage numbers that rise and fade above a piece, shared between game logic and its overlay
+#[derive(Debug, Clone, Default)]
+pub struct DamagePopups {
+    /// Text, color, and spawn time of each popup still on screen
+    popups: Rc<RefCell<Vec<(String, Color, f64)>>>,
+}
+
+impl DamagePopups {
+    /// Initialize with no popups
+    pub fn new() -> Self {
+        DamagePopups::default()
+    }
+
+    /// Show `text`, such as `-3`, in `color`
+    pub fn spawn(&self, text: &str, color: Color) {
+        self.popups.borrow_mut().push((text.to_string(), color, get_time()));
+    }
+}
+
+/// Single overlay along with how it is drawn
+#[derive(Debug, Clone)]
+enum Overlay {
+    /// Health bar or pips in the given color
+    Health(Health, HealthStyle, Color, Anchor),
+
+    /// Row of status icons of the given raw size
+    Status(StatusIcons, f32, Anchor),
+
+    /// Damage popups at the given raw font size
+    Popups(DamagePopups, f32, Anchor),
+}
+
+impl Overlay {
+    /// Draw the overlay on a piece drawn at `location` with drawn size `size` resized using
+    /// `adjustment`
+    fn draw(&self, location: Vec2, size: Vec2, adjustment: f32) {
+        match self {
+            Overlay::Health(health, HealthStyle::Bar { width, height }, color, anchor) => {
+                let own = vec2(*width, *height) * adjustment;
+                let at = anchor.place(location, size, own);
+
+                // Dim background for the missing health, then the remaining health on top
+                draw_rectangle(at.x(), at.y(), own.x(), own.y(), Color::new(0.0, 0.0, 0.0, 0.5));
+                draw_rectangle(at.x(), at.y(), own.x() * health.fraction(), own.y(), *color);
+            }
+            Overlay::Health(health, HealthStyle::Pips { size: pip, spacing }, color, anchor) => {
+                let pip = pip * adjustment;
+                let spacing = spacing * adjustment;
+                let count = health.max() as f32;
+                let own = vec2(pip * count + spacing * (count - 1.0).max(0.0), pip);
+                let at = anchor.place(location, size, own);
+
+                for i in 0..health.max() {
+                    let x = at.x() + (pip + spacing) * i as f32;
+                    if i < health.get() {
+                        draw_rectangle(x, at.y(), pip, pip, *color);
+                    } else {
+                        draw_rectangle_lines(x, at.y(), pip, pip, 1.0, *color);
+                    }
+                }
+            }
+            Overlay::Status(status, icon_size, anchor) => {
+                let icons = status.icons.borrow();
+                let icon_size = icon_size * adjustment;
+                let own = vec2(icon_size * icons.len() as f32, icon_size);
+                let at = anchor.place(location, size, own);
+
+                for (i, icon) in icons.iter().enumerate() {
+                    let params = DrawTextureParams {
+                        dest_size: Some(vec2(icon_size, icon_size)),
+                        source: assets::source(*icon),
+                        ..Default::default()
+                    };
+                    draw_texture_ex(assets::texture(*icon), at.x() + icon_size * i as f32, at.y(),
+                                    WHITE, params);
+                }
+            }
+            Overlay::Popups(popups, font_size, anchor) => {
+                let now = get_time();
+                let mut popups = popups.popups.borrow_mut();
+                popups.retain(|(_, _, spawned)| now - spawned < POPUP_SECONDS);
+
+                for (text, color, spawned) in popups.iter() {
+                    let t = ((now - spawned) / POPUP_SECONDS) as f32;
+                    let (width, height) = measure_text(text, None, *font_size as u16, 1.0);
+                    let own = vec2(width, height) * adjustment;
+                    let at = anchor.place(location, size, own);
+
+                    // Rise and fade over the lifetime of the popup. Text is drawn from its
+                    // baseline, so offset by the height of the text.
+                    let params = TextParams {
+                        font_size: (font_size * adjustment) as u16,
+                        color: Color::new(color.r, color.g, color.b, color.a * (1.0 - t)),
+                        ..Default::default()
+                    };
+                    draw_text_ex(text, at.x(), at.y() + own.y() - POPUP_RISE * adjustment * t,
+                                 params);
+                }
+            }
+        }
+    }
+}
+
+/// Set of overlays drawn on top of a piece through its custom draw hook
+///
+/// ```
+/// let health = Health::new(5);
+/// let status = StatusIcons::new();
+/// let popups = DamagePopups::new();
+///
+/// Overlays::new()
+///     .health(health.clone(), HealthStyle::Pips { size: 6.0, spacing: 2.0 }, RED,
+///             Anchor::new(vec2(0.5, 0.0), vec2(-0.5, -1.0)))
+///     .status(status.clone(), 12.0, Anchor::new(vec2(0.0, 1.0), vec2(0.0, 0.0)))
+///     .popups(popups.clone(), 20.0, Anchor::new(vec2(0.5, 0.0), vec2(-0.5, -1.0)))
+///     .attach(&mut knight);
+///
+/// // Later, in game logic
+/// health.damage(2);
+/// popups.spawn("-2", RED);
+/// status.add("poisoned");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Overlays {
+    /// Overlays drawn in the order they were added
+    overlays: Vec<Overlay>,
+}
+
+impl Overlays {
+    /// Initialize an empty set of overlays
+    pub fn new() -> Self {
+        Overlays::default()
+    }
+
+    /// Add a health overlay drawn in `style` and `color` placed at `anchor`
+    pub fn health(mut self, health: Health, style: HealthStyle, color: Color,
+                  anchor: Anchor) -> Self {
+        self.overlays.push(Overlay::Health(health, style, color, anchor));
+        self
+    }
+
+    /// Add a row of status icons of raw size `icon_size` placed at `anchor`
+    pub fn status(mut self, status: StatusIcons, icon_size: f32, anchor: Anchor) -> Self {
+        self.overlays.push(Overlay::Status(status, icon_size, anchor));
+        self
+    }
+
+    /// Add damage popups at raw `font_size` placed at `anchor`
+    pub fn popups(mut self, popups: DamagePopups, font_size: f32, anchor: Anchor) -> Self {
+        self.overlays.push(Overlay::Popups(popups, font_size, anchor));
+        self
+    }
+
+    /// Draw the overlays on top of `piece`, replacing its custom draw hook. Overlays are resized
+    /// with the piece.
+    pub fn attach(self, piece: &mut Piece) {
+        let raw_width = piece.size().x();
+
+        piece.set_custom_draw(move |location, size| {
+            // Recover the adjustment the piece was drawn with from its drawn size
+            let adjustment = if raw_width > 0.0 { size.x() / raw_width } else { 1.0 };
+
+            for overlay in self.overlays.iter() {
+                overlay.draw(location, size, adjustment);
+            }
+        });
+    }
+}
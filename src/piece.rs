@@ -1,13 +1,62 @@
 use macroquad::*;
-use crate::Resizeable;
-use crate::assets::ASSETS;
+use std::any::Any;
+use std::rc::Rc;
+use crate::{Layout, Resizeable};
+use crate::assets::{self, AssetKey};
+use crate::label::Label;
+
+/// What a `Piece` draws for itself underneath its children
+#[derive(Debug, Clone)]
+enum Content {
+    /// Texture, or atlas region, by texture ID
+    Texture(u32),
+
+    /// Text label
+    Label(Label),
+
+    /// Nested layout container, such as a `Column`
+    Layout(Box<dyn AnyLayout>),
+}
+
+/// `Layout` that can be cloned and downcast back to its concrete type, so a `Piece` holding a
+/// layout stays `Clone` and the layout can be changed after it is wrapped
+trait AnyLayout: Layout {
+    /// Clone the layout into a new box
+    fn clone_box(&self) -> Box<dyn AnyLayout>;
+
+    /// Get the layout as `Any` to downcast it
+    fn as_any(&self) -> &dyn Any;
+
+    /// Get the mutable layout as `Any` to downcast it
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<L: Layout + Clone + 'static> AnyLayout for L {
+    fn clone_box(&self) -> Box<dyn AnyLayout> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Clone for Box<dyn AnyLayout> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
 
 /// Indiviual piece with potential children pieces that are drawn in relation to this `Piece`s 
 /// location
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Piece {
-    /// Texture for the current piece
-    texture: u32,
+    /// Texture or label drawn for the current piece
+    content: Content,
 
     /// Vec of children that are drawn in relation to this `Piece`
     /// (Piece, Relation to parent, Relation to self)
@@ -31,13 +80,74 @@ pub struct Piece {
     ///
     ///
     children: Vec<(Piece, Vec2, Vec2)>,
+
+    /// Optional hook invoked after the texture and children are drawn, used for bespoke
+    /// decorations such as health arcs or cooldown pies. Called with the drawn location and the
+    /// drawn size of this `Piece`'s texture. The hook can capture per-piece state, such as a
+    /// shared health value.
+    custom_draw: Option<Rc<dyn Fn(Vec2, Vec2)>>,
+
+    /// Rotation in radians around the center of this `Piece`'s texture. For children, this
+    /// rotation is relative to the rotation of the parent.
+    rotation: f32,
+
+    /// Color the texture is tinted with. `None` inherits the tint of the parent, or draws untinted
+    /// (`WHITE`) for a `Piece` without a parent.
+    tint: Option<Color>,
+
+    /// Opacity of this `Piece` from `0.0` to `1.0`. Children are multiplied by the alpha of their
+    /// parent.
+    alpha: f32,
+
+    /// Draw order of this `Piece` relative to its siblings. Higher values are drawn on top.
+    /// Children with a negative z-index are drawn behind their parent.
+    z_index: i32,
 }
 
 impl Piece {
-    pub fn new(texture: u32) -> Self {
+    /// Create a new `Piece` from a texture name registered with an `AssetLoader` or a raw
+    /// texture ID
+    pub fn new<K: AssetKey>(texture: K) -> Self {
+        Piece::with_content(Content::Texture(texture.asset_id()))
+    }
+
+    /// Create a new `Piece` that draws `label` instead of a texture, so text can be added to a
+    /// `Row` or as a child of another `Piece`. Labels are drawn unrotated.
+    pub fn from_label(label: Label) -> Self {
+        Piece::with_content(Content::Label(label))
+    }
+
+    /// Create a new `Piece` that draws `layout`, so containers can be nested, such as a `Row` of
+    /// `Column`s. The layout is drawn unrotated and untinted using the adjustment of the
+    /// container the `Piece` is in.
+    pub fn from_layout<L: Layout + Clone + 'static>(layout: L) -> Self {
+        Piece::with_content(Content::Layout(Box::new(layout)))
+    }
+
+    /// Start building a `Piece` from a texture name or raw texture ID, so pieces with several
+    /// children can be constructed in a single expression
+    ///
+    /// ```
+    /// let card = Piece::builder("card")
+    ///     .child(Piece::new("suit"), vec2(0.1, 0.1), vec2(0.0, 0.0))
+    ///     .tint(RED)
+    ///     .rotation(0.5)
+    ///     .build();
+    /// ```
+    pub fn builder<K: AssetKey>(texture: K) -> PieceBuilder {
+        PieceBuilder { piece: Piece::new(texture) }
+    }
+
+    /// Create a new `Piece` drawing `content` with default settings
+    fn with_content(content: Content) -> Self {
         Piece {
-            texture,
-            children: Vec::new()
+            content,
+            children: Vec::new(),
+            custom_draw: None,
+            rotation: 0.0,
+            tint: None,
+            alpha: 1.0,
+            z_index: 0
         }
     }
 
@@ -47,114 +157,461 @@ impl Piece {
         self.children.push((piece, rel_parent, rel_self));
     }
 
+    /// Remove and return the child at `index`
+    pub fn remove_child(&mut self, index: usize) -> Piece {
+        self.children.remove(index).0
+    }
+
+    /// Replace the child at `index` with `piece`, keeping the same relation to the parent, and
+    /// return the previous child
+    pub fn replace_child(&mut self, index: usize, piece: Piece) -> Piece {
+        std::mem::replace(&mut self.children[index].0, piece)
+    }
+
+    /// Remove all children from this `Piece`
+    pub fn clear_children(&mut self) {
+        self.children.clear();
+    }
+
+    /// Iterate over the children of this `Piece` in the order they were added
+    pub fn children(&self) -> impl Iterator<Item = &Piece> {
+        self.children.iter().map(|(child, _, _)| child)
+    }
+
+    /// Mutably iterate over the children of this `Piece` in the order they were added
+    pub fn children_mut(&mut self) -> impl Iterator<Item = &mut Piece> {
+        self.children.iter_mut().map(|(child, _, _)| child)
+    }
+
+    /// Set a hook that is called after this `Piece` is drawn with the drawn location and size of
+    /// the `Piece`'s texture
+    ///
+    /// ```
+    /// let health = Rc::new(Cell::new(1.0));
+    /// let hook_health = health.clone();
+    /// piece.set_custom_draw(move |location, size| {
+    ///     draw_rectangle(location.x(), location.y() - 4.0, size.x() * hook_health.get(), 4.0,
+    ///                    GREEN);
+    /// });
+    /// ```
+    pub fn set_custom_draw<F: Fn(Vec2, Vec2) + 'static>(&mut self, custom_draw: F) {
+        self.custom_draw = Some(Rc::new(custom_draw));
+    }
+
+    /// Remove the custom draw hook of this `Piece`
+    pub fn clear_custom_draw(&mut self) {
+        self.custom_draw = None;
+    }
+
+    /// Set the rotation in radians around the center of this `Piece`'s texture
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+    }
+
+    /// Get the rotation in radians of this `Piece`
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Set the color to tint this `Piece` with. For children, this overrides the parent tint.
+    pub fn set_tint(&mut self, tint: Color) {
+        self.tint = Some(tint);
+    }
+
+    /// Remove the tint of this `Piece` so that it inherits the tint of its parent
+    pub fn clear_tint(&mut self) {
+        self.tint = None;
+    }
+
+    /// Set the opacity of this `Piece` from `0.0` (invisible) to `1.0` (opaque)
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha;
+    }
+
+    /// Get the opacity of this `Piece`
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Set the draw order of this `Piece` relative to its siblings. Higher values are drawn on
+    /// top.
+    pub fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    /// Get the draw order of this `Piece`
+    pub fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    /// Get the color to draw this `Piece` with given the `inherited` color of its parent
+    fn color(&self, inherited: Color) -> Color {
+        match self.tint {
+            // Override the parent tint while still fading with the parent
+            Some(tint) => Color::new(tint.r, tint.g, tint.b, tint.a * inherited.a * self.alpha),
+            None => Color::new(inherited.r, inherited.g, inherited.b, inherited.a * self.alpha),
+        }
+    }
+
     /// Get the `Texture2D` of this `Piece`
+    ///
+    /// Panics for label and layout pieces, which have no texture. Use `try_texture` when the
+    /// piece may not be a texture piece.
     pub fn texture(&self) -> Texture2D {
-        *ASSETS.get().expect("ASSETS not set")
-               .get(&self.texture).expect("Texture not set in child")
+        self.try_texture().expect("Piece has no texture")
     }
 
-    /// Get the width of the `Texture2D` of this piece. 
+    /// Get the `Texture2D` of this `Piece`. Returns `None` for label and layout pieces.
+    pub fn try_texture(&self) -> Option<Texture2D> {
+        match &self.content {
+            Content::Texture(id) => Some(assets::texture(*id)),
+            _ => None,
+        }
+    }
+
+    /// Get the label of this `Piece` if it is a label piece
+    pub fn label(&self) -> Option<&Label> {
+        match &self.content {
+            Content::Label(label) => Some(label),
+            _ => None,
+        }
+    }
+
+    /// Get the mutable label of this `Piece` if it is a label piece, such as to update a score
+    pub fn label_mut(&mut self) -> Option<&mut Label> {
+        match &mut self.content {
+            Content::Label(label) => Some(label),
+            _ => None,
+        }
+    }
+
+    /// Get the layout of this `Piece` if it is a layout piece holding an `L`
+    pub fn layout<L: Layout + Clone + 'static>(&self) -> Option<&L> {
+        match &self.content {
+            Content::Layout(layout) => layout.as_any().downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Get the mutable layout of this `Piece` if it is a layout piece holding an `L`, such as to
+    /// add to one `Column` in a `Row` of `Column`s.
     ///
-    /// Since it's possible for children's textures can extend past the bounds of the parent 
-    /// texture, the calculation must be done to know how far the children extend in order to
-    /// return a true width of this `Piece`.
-    pub fn width(&self) -> f32 {
-        let mut left  = 0.0;
-        let mut right = self.texture().width();
+    /// Containers measure their items when they are added, so take the piece out of its
+    /// container before changing the size of its layout and put it back afterwards.
+    ///
+    /// ```
+    /// let mut piece = row.remove(1);
+    /// piece.layout_mut::<Column>().unwrap().add(Piece::new("card"));
+    /// row.insert(1, piece);
+    /// ```
+    pub fn layout_mut<L: Layout + Clone + 'static>(&mut self) -> Option<&mut L> {
+        match &mut self.content {
+            Content::Layout(layout) => layout.as_any_mut().downcast_mut(),
+            _ => None,
+        }
+    }
 
-        for (child, _rel_parent, rel_self) in self.children.iter() {
-            // Get the child texture from the texture ID
-            let child_texture = child.texture();
+    /// Get the source rectangle in the atlas texture if this `Piece` uses an atlas region
+    pub fn source(&self) -> Option<Rect> {
+        match &self.content {
+            Content::Texture(id) => assets::source(*id),
+            _ => None,
+        }
+    }
 
-            // Check if left edge extends past top of parent texture
-            let child_x_offset = child_texture.width()  * rel_self.x();
-            if child_x_offset < left {
-                left = child_x_offset;
-            }
+    /// Get the raw size of this `Piece`'s own texture, atlas region, label, or layout without
+    /// its children
+    pub fn size(&self) -> Vec2 {
+        match &self.content {
+            Content::Texture(id) => assets::size(*id),
+            Content::Label(label) => label.size(),
+            Content::Layout(layout) => layout.raw_size(),
+        }
+    }
+
+    /// Draw only this `Piece`'s own texture or label, without its children, with its unrotated
+    /// top left at `origin` resized using `adjustment`. Textures are rotated around their center.
+    fn draw_content(&self, origin: Vec2, adjustment: f32, rotation: f32, color: Color) {
+        match &self.content {
+            Content::Texture(id) => {
+                // Resize the image to fit the screen width, only drawing the atlas region if
+                // there is one
+                let params = DrawTextureParams {
+                    dest_size: Some(self.size() * adjustment),
+                    source: self.source(),
+                    rotation,
+                    ..Default::default()
+                };
 
-            // Check if right edge extends past top of parent texture
-            let curr_right = child_x_offset + child_texture.width();
-            if curr_right > right {
-                right = curr_right;
+                draw_texture_ex(assets::texture(*id), origin.x(), origin.y(), color, params);
             }
+            Content::Label(label) => label.draw_tinted(origin, adjustment, color),
+            Content::Layout(layout) => layout.draw(origin, adjustment),
         }
+    }
 
-        // Return true width of this piece
-        right - left
+    /// Get the width of the `Texture2D` of this piece. 
+    ///
+    /// Since it's possible for children's textures can extend past the bounds of the parent 
+    /// texture, the calculation must be done to know how far the children extend in order to
+    /// return a true width of this `Piece`. If the piece is rotated, this is the width of the
+    /// rotated bounding box.
+    pub fn width(&self) -> f32 {
+        let extent = self.unrotated_extent();
+        rotated_bounds(vec2(extent.w, extent.h), self.rotation).x()
     }
 
     /// Get the height of the `Texture2D` of this piece. 
     ///
     /// Since it's possible for children's textures can extend past the bounds of the parent 
     /// texture, the calculation must be done to know how far the children extend in order to
-    /// return a true height of this `Piece`.
+    /// return a true height of this `Piece`. If the piece is rotated, this is the height of the
+    /// rotated bounding box.
     pub fn height(&self) -> f32 {
-        let mut top  = 0.0;
-        let mut bottom = self.texture().height();
+        let extent = self.unrotated_extent();
+        rotated_bounds(vec2(extent.w, extent.h), self.rotation).y()
+    }
+
+    /// Get the raw extent of this piece, including children, before rotation is applied. The
+    /// extent is relative to the top left of this `Piece`'s texture, so children extending past
+    /// the top or left of the texture give a negative `x` or `y`.
+    fn unrotated_extent(&self) -> Rect {
+        let mut left   = 0.0;
+        let mut top    = 0.0;
+        let mut right  = self.size().x();
+        let mut bottom = self.size().y();
 
         for (child, _rel_parent, rel_self) in self.children.iter() {
-            // Get the child texture from the texture ID
-            let child_texture = child.texture();
+            // Get the child size from the texture ID
+            let child_size = child.size();
 
-            // Check if top edge extends past top of parent texture
-            let child_y_offset = child_texture.height()  * rel_self.y();
-            if child_y_offset < top {
-                top = child_y_offset;
-            }
+            // Check if left or top edge extends past the parent texture
+            let child_x_offset = child_size.x() * rel_self.x();
+            let child_y_offset = child_size.y() * rel_self.y();
+            left = f32::min(left, child_x_offset);
+            top  = f32::min(top,  child_y_offset);
 
-            // Check if bottom edge extends past top of parent texture
-            let curr_bottom = child_y_offset + child_texture.height();
-            if curr_bottom > bottom {
-                bottom = curr_bottom;
-            }
+            // Check if right or bottom edge extends past the parent texture
+            right  = f32::max(right,  child_x_offset + child_size.x());
+            bottom = f32::max(bottom, child_y_offset + child_size.y());
+        }
+
+        Rect::new(left, top, right - left, bottom - top)
+    }
+
+    /// Get the unrotated top left of this `Piece`'s texture when drawn at `location` resized
+    /// using `adjustment`.
+    ///
+    /// The piece is rotated around the center of its texture, so the texture is shifted to keep
+    /// the rotated bounding box of the whole piece, children included, starting at `location`.
+    /// This is the same box measured by `width` and `height`.
+    fn origin(&self, location: Vec2, adjustment: f32) -> Vec2 {
+        let extent = self.unrotated_extent();
+        let center = self.size() / 2.0;
+
+        // Find the top left of the rotated bounding box relative to the texture's top left
+        let corners = [
+            vec2(extent.x, extent.y),
+            vec2(extent.x + extent.w, extent.y),
+            vec2(extent.x, extent.y + extent.h),
+            vec2(extent.x + extent.w, extent.y + extent.h),
+        ];
+        let mut min = rotate_around(corners[0], center, self.rotation);
+        for corner in &corners[1..] {
+            min = min.min(rotate_around(*corner, center, self.rotation));
+        }
+
+        location - min * adjustment
+    }
+
+    /// Get the unrotated screen rectangle of the given `child` when this `Piece`'s texture is
+    /// drawn unrotated with its top left at `origin` resized using `adjustment`
+    fn child_rect(&self, origin: Vec2, adjustment: f32, child: &Piece, rel_parent: Vec2,
+                  rel_self: Vec2) -> Rect {
+        let size = self.size();
+        let child_size = child.size();
+
+        // Offset relative to the size of the parent texture
+        let mut x_offset = origin.x() + size.x() * adjustment * rel_parent.x();
+        let mut y_offset = origin.y() + size.y() * adjustment * rel_parent.y();
+
+        // Calculate x,y offset relative to the child itself
+        x_offset += child_size.x() * adjustment * rel_self.x();
+        y_offset += child_size.y() * adjustment * rel_self.y();
+
+        Rect::new(x_offset, y_offset, child_size.x() * adjustment, child_size.y() * adjustment)
+    }
+
+    /// Returns `true` if `point` lands on this `Piece` or any of its children when the `Piece` is
+    /// drawn at `location` resized using `adjustment`
+    pub fn contains(&self, point: Vec2, location: Vec2, adjustment: f32) -> bool {
+        let size = self.size() * adjustment;
+        let origin = self.origin(location, adjustment);
+
+        // Undo the rotation of the piece so the point can be checked against unrotated rects
+        let point = rotate_around(point, origin + size / 2.0, -self.rotation);
+
+        let parent = Rect::new(origin.x(), origin.y(), size.x(), size.y());
+        if rect_contains(&parent, point) {
+            return true;
         }
 
-        // Return true height of this piece
-        bottom - top
+        // Children can extend outside of the parent texture, so check each of them as well
+        self.children.iter().any(|(child, rel_parent, rel_self)| {
+            let rect = self.child_rect(origin, adjustment, child, *rel_parent, *rel_self);
+
+            // Undo the relative rotation of the child around its own center
+            let center = vec2(rect.x + rect.w / 2.0, rect.y + rect.h / 2.0);
+            rect_contains(&rect, rotate_around(point, center, -child.rotation))
+        })
+    }
+}
+
+/// Get the size of the bounding box of a rectangle of `size` rotated by `rotation` radians
+fn rotated_bounds(size: Vec2, rotation: f32) -> Vec2 {
+    let (sin, cos) = rotation.sin_cos();
+    vec2(size.x() * cos.abs() + size.y() * sin.abs(),
+         size.x() * sin.abs() + size.y() * cos.abs())
+}
+
+/// Rotate `point` around `pivot` by `rotation` radians
+fn rotate_around(point: Vec2, pivot: Vec2, rotation: f32) -> Vec2 {
+    let (sin, cos) = rotation.sin_cos();
+    let offset = point - pivot;
+    pivot + vec2(offset.x() * cos - offset.y() * sin, offset.x() * sin + offset.y() * cos)
+}
+
+/// Fluent builder for a `Piece` created by `Piece::builder`
+#[derive(Debug, Clone)]
+pub struct PieceBuilder {
+    /// Piece being built
+    piece: Piece,
+}
+
+impl PieceBuilder {
+    /// Add a child `Piece`. See `Piece::add_child`.
+    pub fn child(mut self, piece: Piece, rel_parent: Vec2, rel_self: Vec2) -> Self {
+        self.piece.add_child(piece, rel_parent, rel_self);
+        self
+    }
+
+    /// Set the custom draw hook. See `Piece::set_custom_draw`.
+    pub fn custom_draw<F: Fn(Vec2, Vec2) + 'static>(mut self, custom_draw: F) -> Self {
+        self.piece.set_custom_draw(custom_draw);
+        self
+    }
+
+    /// Set the rotation in radians. See `Piece::set_rotation`.
+    pub fn rotation(mut self, rotation: f32) -> Self {
+        self.piece.set_rotation(rotation);
+        self
+    }
+
+    /// Set the tint. See `Piece::set_tint`.
+    pub fn tint(mut self, tint: Color) -> Self {
+        self.piece.set_tint(tint);
+        self
+    }
+
+    /// Set the opacity. See `Piece::set_alpha`.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.piece.set_alpha(alpha);
+        self
+    }
+
+    /// Set the draw order relative to siblings. See `Piece::set_z_index`.
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.piece.set_z_index(z_index);
+        self
+    }
+
+    /// Finish building the `Piece`
+    pub fn build(self) -> Piece {
+        self.piece
+    }
+}
+
+/// Returns `true` if `point` is inside of `rect`
+fn rect_contains(rect: &Rect, point: Vec2) -> bool {
+    point.x() >= rect.x && point.x() <= rect.x + rect.w &&
+    point.y() >= rect.y && point.y() <= rect.y + rect.h
+}
+
+impl std::fmt::Debug for Piece {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // The custom draw hook is a closure, so only show whether there is one
+        f.debug_struct("Piece")
+         .field("content", &self.content)
+         .field("children", &self.children)
+         .field("custom_draw", &self.custom_draw.is_some())
+         .field("rotation", &self.rotation)
+         .field("tint", &self.tint)
+         .field("alpha", &self.alpha)
+         .field("z_index", &self.z_index)
+         .finish()
+    }
+}
+
+impl Layout for Piece {
+    fn raw_size(&self) -> Vec2 {
+        vec2(self.width(), self.height())
     }
 }
 
 impl Resizeable for Piece {
     fn draw(&self, location: Vec2, adjustment: f32) {
-        let x_coord = location.x();
-        let y_coord = location.y();
+        let parent_width = self.size().x() * adjustment;
+        let parent_height = self.size().y() * adjustment;
+
+        // Get the unrotated top left and center of the texture
+        let origin = self.origin(location, adjustment);
+        let center = origin + vec2(parent_width, parent_height) / 2.0;
 
-        // Get the texture from the texture ID
-        let texture = self.texture();
+        // Children inherit the parent tint unless they have their own
+        let color = self.color(WHITE);
 
-        let parent_width = texture.width() * adjustment;
-        let parent_height = texture.height() * adjustment;
+        let draw_child = |(child, rel_parent, rel_self): &(Piece, Vec2, Vec2)| {
+            // Get the location of the child based on the size of the parent texture
+            let rect = self.child_rect(origin, adjustment, child, *rel_parent, *rel_self);
 
-        // Resize the image to fit the screen width
-        let params = DrawTextureParams {
-            dest_size: Some(vec2(parent_width, parent_height)),
-            ..Default::default()
+            // Rotate the center of the child around the center of the parent
+            let child_center = rotate_around(vec2(rect.x + rect.w / 2.0, rect.y + rect.h / 2.0),
+                                             center, self.rotation);
+
+            // Draw the child with its rotation relative to the parent
+            let child_origin = child_center - vec2(rect.w, rect.h) / 2.0;
+            child.draw_content(child_origin, adjustment, self.rotation + child.rotation,
+                               child.color(color));
+
+            // Draw any custom decorations of the child on top of the child
+            if let Some(custom_draw) = &child.custom_draw {
+                custom_draw(child_origin, vec2(rect.w, rect.h));
+            }
         };
 
-        // Draw the texture at the calculated location
-        draw_texture_ex(texture, x_coord, y_coord, WHITE, params);
+        // Sort the children by z-index. The sort is stable, so children with the same z-index are
+        // drawn in the order they were added.
+        let mut children: Vec<&(Piece, Vec2, Vec2)> = self.children.iter().collect();
+        children.sort_by_key(|(child, _, _)| child.z_index);
 
-        for (child, rel_parent, rel_self) in self.children.iter() {
-            // Draw the texture for the child at the calculated location based on the size of the
-            // parent texture
-            let mut x_offset = x_coord + parent_width  * rel_parent.x();
-            let mut y_offset = y_coord + parent_height * rel_parent.y();
+        // Children with a negative z-index are drawn behind the parent texture
+        let split = children.iter().position(|(child, _, _)| child.z_index >= 0)
+                            .unwrap_or(children.len());
 
-            // Get the child texture from the texture ID
-            let child_texture = child.texture();
+        for child in &children[..split] {
+            draw_child(*child);
+        }
 
-            // Calculate x,y offset relative to the child itself
-            x_offset += child_texture.width()  * adjustment * rel_self.x();
-            y_offset += child_texture.height() * adjustment * rel_self.y();
+        // Draw the texture at the calculated location with the tint of this piece
+        self.draw_content(origin, adjustment, self.rotation, color);
 
-           // Resize the image to fit the screen width
-            let params = DrawTextureParams {
-                dest_size: Some(vec2(child_texture.width()  * adjustment, 
-                                     child_texture.height() * adjustment)),
-                ..Default::default()
-            };
+        for child in &children[split..] {
+            draw_child(*child);
+        }
 
-            draw_texture_ex(child_texture, x_offset, y_offset, WHITE, params);
+        // Draw any custom decorations on top of the standard render
+        if let Some(custom_draw) = &self.custom_draw {
+            custom_draw(origin, vec2(parent_width, parent_height));
         }
     }
 }
@@ -1,6 +1,44 @@
 use macroquad::*;
-use crate::Resizeable;
+use crate::{Layout, Resizeable};
 use crate::piece::Piece;
+use crate::background::Fill;
+use crate::layer::Layer;
+use crate::scale::{Scale, ScaleMode};
+
+/// Vertical alignment of items shorter than the tallest item in a `Row`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum VerticalAlign {
+    /// Align the top of each item with the top of the `Row`
+    #[default]
+    Top,
+
+    /// Center each item against the tallest item in the `Row`
+    Center,
+
+    /// Align the bottom of each item with the bottom of the `Row`
+    Bottom,
+}
+
+/// Horizontal placement of the items when they don't fill the entire width of the screen
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Justify {
+    /// Pack the items against the left of the screen
+    #[default]
+    Start,
+
+    /// Pack the items in the center of the screen
+    Center,
+
+    /// Pack the items against the right of the screen
+    End,
+
+    /// Put the first and last items against the edges and distribute the remaining space evenly
+    /// between the items
+    SpaceBetween,
+
+    /// Distribute the remaining space evenly around each item, with half a gap on each edge
+    SpaceAround,
+}
 
 #[derive(Default, Debug, Clone)]
 /// Collections of items that will be displayed on the same Row on screen that is ready to be
@@ -22,7 +60,19 @@ pub struct Row {
     raw_height: f32,
 
     /// Number of pixels to put between each element for even horizontal spacing
-    spacing: f32
+    spacing: f32,
+
+    /// Optional procedural fill drawn behind the items, sized to the entire `Row`
+    background: Option<Fill>,
+
+    /// Vertical alignment of items shorter than the tallest item
+    align: VerticalAlign,
+
+    /// Horizontal placement of the items when they don't fill the width of the screen
+    justify: Justify,
+
+    /// How the resize adjustment is calculated. Defaults to filling the width of the screen.
+    scale: Scale,
 }
 
 impl Row {
@@ -33,93 +83,243 @@ impl Row {
 
     /// Set the new spacing and recalculating the raw dimensions using the new spacing
     pub fn spacing(&mut self, spacing: f32) {
+        // Set the new spacing
+        self.spacing = spacing;
+
+        self.recalculate();
+    }
+
+    /// Recalculate the raw dimensions from the current items and spacing
+    fn recalculate(&mut self) {
         // Calculate the raw width of only the current items
         let items_width: f32 = self.items.iter().map(|x| x.width()).sum();
 
-        // Re-calculate raw width with new spacing. Spacing on the left and right borders
-        self.raw_width = spacing * (self.items.len() + 1) as f32 + items_width;
+        // Re-calculate raw width with the spacing
+        self.raw_width = self.bordered_width(items_width, self.items.len());
 
-        // Re-calculate raw width with new spacing. Spacing on the top and bottom borders
-        self.raw_height = spacing * 2.0;
-
-        // Update raw height with found max height of the row
+        // Find the max height of the row
         let mut max_height = 0.0;
         for item in self.items.iter() {
             if item.height() > max_height {
                 max_height = item.height();
             }
         }
-        self.raw_height += max_height;
 
-        // Set the new spacing
-        self.spacing = spacing;
+        // Re-calculate raw height with the spacing
+        self.raw_height = self.bordered_height(max_height);
+    }
+
+    /// Get the raw width of `count` items that are `items_width` wide in total. Spacing between
+    /// each item and on the left and right borders.
+    fn bordered_width(&self, items_width: f32, count: usize) -> f32 {
+        self.spacing * (count + 1) as f32 + items_width
+    }
+
+    /// Get the raw height of items where the tallest is `max_height`. Spacing on the top and
+    /// bottom borders.
+    fn bordered_height(&self, max_height: f32) -> f32 {
+        self.spacing * 2.0 + max_height
+    }
+
+    /// Set the vertical alignment of items shorter than the tallest item in the `Row`
+    pub fn align(&mut self, align: VerticalAlign) {
+        self.align = align;
+    }
+
+    /// Set the horizontal placement of the items when they don't fill the width of the screen
+    pub fn justify(&mut self, justify: Justify) {
+        self.justify = justify;
+    }
+
+    /// Set how the resize adjustment is calculated. Use `ScaleMode::Fixed(1.0)` with `justify`
+    /// to draw the items at their raw size.
+    pub fn scale_mode(&mut self, mode: ScaleMode) {
+        self.scale.mode = mode;
+    }
+
+    /// Cap the resize adjustment at `max` so the items don't grow past that scale on large
+    /// screens
+    pub fn max_scale(&mut self, max: f32) {
+        self.scale.max = Some(max);
+    }
+
+    /// Set a procedural fill to be drawn behind the items covering the entire `Row`
+    pub fn background(&mut self, fill: Fill) {
+        self.background = Some(fill);
     }
 
     /// Add an element to the current `Row` and update the raw dimensions based on the new element
     pub fn add(&mut self, item: Piece) {
-        self.raw_width += item.width() + self.spacing;
-        if (item.height() + self.spacing) > self.raw_height {
-            self.raw_height = item.height();
-        }
+        // Grow the current dimensions by the new item rather than re-measuring every item
+        let items_width = self.raw_width - self.bordered_width(0.0, self.items.len());
+        let max_height  = (self.raw_height - self.bordered_height(0.0)).max(item.height());
+
+        self.raw_width  = self.bordered_width(items_width + item.width(), self.items.len() + 1);
+        self.raw_height = self.bordered_height(max_height);
 
         self.items.push(item);
 
         info!("{}: {}", self.items.len(), self.raw_width);
     }
-    
+
+    /// Insert an element at `index` in the current `Row` and update the raw dimensions
+    pub fn insert(&mut self, index: usize, item: Piece) {
+        self.items.insert(index, item);
+        self.recalculate();
+    }
+
+    /// Remove and return the element at `index` in the current `Row` and update the raw
+    /// dimensions
+    pub fn remove(&mut self, index: usize) -> Piece {
+        let item = self.items.remove(index);
+        self.recalculate();
+        item
+    }
+
+    /// Remove all elements from the current `Row`
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.recalculate();
+    }
+
+    /// Get the number of elements in the current `Row`
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the current `Row` has no elements
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
     /// Get the current adjusted height of the `Row`
     pub fn height(&self) -> f32 {
-        // Calculate the adjustment fraction to fill the entire screen
-        let adjustment = screen_width() as f32 / self.raw_width;
+        self.raw_height * self.adjustment()
+    }
 
-        self.raw_height * adjustment
+    /// Get the current resize adjustment used to stretch the `Row` across the screen
+    fn adjustment(&self) -> f32 {
+        self.scale.adjustment(self.raw_width, self.raw_height)
     }
 
-    pub fn draw(&self, location: Vec2) {
-        let adjustment = screen_width() / self.raw_width;
+    /// Get the drawn location of each item in the `Row` when the `Row` is drawn at `location`
+    /// resized using `adjustment` and justified within `width`
+    fn item_locations(&self, location: Vec2, adjustment: f32, width: f32) -> Vec<Vec2> {
+        // Width not filled by the row to distribute based on the justification
+        let remaining = (width - self.raw_width * adjustment).max(0.0);
+        let count = self.items.len() as f32;
+
+        let (start_offset, gap) = match self.justify {
+            Justify::Start  => (0.0, 0.0),
+            Justify::Center => (remaining / 2.0, 0.0),
+            Justify::End    => (remaining, 0.0),
+            Justify::SpaceBetween if self.items.len() > 1 => (0.0, remaining / (count - 1.0)),
+            Justify::SpaceBetween => (remaining / 2.0, 0.0),
+            Justify::SpaceAround  => (remaining / count / 2.0, remaining / count),
+        };
 
         // Initialize the current X position from the given starting X position
-        let mut curr_x = location.x() + self.spacing * adjustment;
+        let mut curr_x = location.x() + start_offset + self.spacing * adjustment;
 
         // Initialize the current Y position
         let curr_y = location.y() + self.spacing * adjustment;
 
-        // Draw each item in the row with the found adjustment
-        for item in &self.items {
-            // Draw the texture at the calculated location
-            // draw_texture_ex(item.texture(), curr_x, curr_y, WHITE, params);
-            item.draw(vec2(curr_x, curr_y), adjustment);
+        // Height of the tallest item that the other items are aligned against
+        let max_height = self.raw_height - self.spacing * 2.0;
 
-            // let extension = item.width() - item.texture().width();
-            let extension = 0.0;
+        let mut locations = Vec::with_capacity(self.items.len());
+        for item in &self.items {
+            // Offset shorter items based on the alignment
+            let y_offset = match self.align {
+                VerticalAlign::Top    => 0.0,
+                VerticalAlign::Center => (max_height - item.height()) / 2.0,
+                VerticalAlign::Bottom => max_height - item.height(),
+            };
 
-            // draw_rectangle(curr_x, curr_y, item.width(), item.height(), GREEN);
+            locations.push(vec2(curr_x, curr_y + y_offset * adjustment));
 
             // Update X position for the current item
-            curr_x += item.width() * adjustment + self.spacing * adjustment + extension;
+            curr_x += item.width() * adjustment + self.spacing * adjustment + gap;
         }
+
+        locations
     }
-}
 
-/*
-impl Resizeable for Row {
-    fn draw(&self, location: Vec2, adjustment: f32) {
-        // Initialize the current X position from the given starting X position
-        let mut curr_x = location.x() + self.spacing * adjustment;
+    /// Get the index of the item under `point` when the `Row` is drawn at `location`. Children
+    /// that extend outside of an item's texture are included in the hit test.
+    pub fn hit_test(&self, point: Vec2, location: Vec2) -> Option<usize> {
+        let adjustment = self.adjustment();
 
-        // Initialize the current Y position
-        let curr_y = location.y();
+        // Check in reverse draw order so the item drawn on top wins
+        let mut items: Vec<(usize, Vec2)> = self.item_locations(location, adjustment,
+                                                                screen_width())
+                                                .into_iter().enumerate().collect();
+        items.sort_by_key(|(i, _)| self.items[*i].z_index());
+
+        items.iter().rev()
+             .find(|(i, item_location)| self.items[*i].contains(point, *item_location, adjustment))
+             .map(|(i, _)| *i)
+    }
+
+    pub fn draw(&self, location: Vec2) {
+        // Justify across the screen, with the background spanning the screen even if the items
+        // don't
+        self.draw_within(location, self.adjustment(), screen_width());
+    }
+
+    /// Draw the `Row` at `location` resized using `adjustment` with the items justified within
+    /// `width`
+    fn draw_within(&self, location: Vec2, adjustment: f32, width: f32) {
+        // Draw the background behind the items sized to the entire row
+        if let Some(fill) = &self.background {
+            let width = (self.raw_width * adjustment).max(width);
+            fill.draw(location.x(), location.y(), width, self.raw_height * adjustment);
+        }
+
+        // Sort the items by z-index so overlapping items are drawn in the right order. The sort
+        // is stable, so items with the same z-index are drawn left to right.
+        let mut items: Vec<(&Piece, Vec2)> = self.items.iter()
+                                                 .zip(self.item_locations(location, adjustment,
+                                                                          width))
+                                                 .collect();
+        items.sort_by_key(|(item, _)| item.z_index());
 
         // Draw each item in the row with the found adjustment
-        for item in &self.items {
-            // Draw the texture at the calculated location
-            // draw_texture_ex(item.texture(), curr_x, curr_y, WHITE, params);
-            item.draw(vec2(curr_x, curr_y), adjustment);
+        for (item, item_location) in items {
+            item.draw(item_location, adjustment);
+        }
+    }
 
-            // Update X position for the current item
-            curr_x += item.width() * adjustment + self.spacing * adjustment;
+    /// Add each item in the `Row` drawn at `location` to `layer`, so the items are sorted with
+    /// everything else in the layer instead of being drawn immediately. The background is added
+    /// just below the lowest item.
+    pub fn add_to_layer<'a>(&'a self, layer: &mut Layer<'a>, location: Vec2) {
+        let adjustment = self.adjustment();
+
+        if let Some(fill) = self.background {
+            let z_index = self.items.iter().map(|item| item.z_index()).min().unwrap_or(0);
+            let width = (self.raw_width * adjustment).max(screen_width());
+            layer.push_fill(z_index.saturating_sub(1), fill, location,
+                            vec2(width, self.raw_height * adjustment));
+        }
+
+        let locations = self.item_locations(location, adjustment, screen_width());
+        for (item, item_location) in self.items.iter().zip(locations) {
+            layer.push_piece(item, item_location, adjustment);
         }
     }
 }
-*/
 
+impl Resizeable for Row {
+    /// Draw the `Row` nested inside of another container. The items are packed together since
+    /// there is no extra width to justify them within.
+    fn draw(&self, location: Vec2, adjustment: f32) {
+        self.draw_within(location, adjustment, self.raw_width * adjustment);
+    }
+}
+
+impl Layout for Row {
+    fn raw_size(&self) -> Vec2 {
+        vec2(self.raw_width, self.raw_height)
+    }
+}
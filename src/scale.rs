@@ -0,0 +1,69 @@
+use macroquad::*;
+
+/// How a layout container calculates the resize adjustment from its raw size and the screen size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScaleMode {
+    /// Stretch to fill the width of the screen
+    FitWidth,
+
+    /// Stretch to fill the height of the screen
+    FitHeight,
+
+    /// Stretch as large as possible while fitting entirely on the screen
+    Contain,
+
+    /// Always use the given adjustment regardless of the screen size. `Fixed(1.0)` draws at the
+    /// raw size.
+    Fixed(f32),
+}
+
+/// Scale mode along with an optional cap on the resulting adjustment, so content doesn't become
+/// comically large on big screens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale {
+    /// How the adjustment is calculated
+    pub mode: ScaleMode,
+
+    /// Largest adjustment allowed, if any
+    pub max: Option<f32>,
+}
+
+impl Scale {
+    /// Create a new `Scale` using `mode` without a cap
+    pub fn new(mode: ScaleMode) -> Self {
+        Scale { mode, max: None }
+    }
+
+    /// Get the adjustment for content of the given raw size on the current screen. Content with
+    /// no raw size in the fitted dimension, such as an empty `Row`, is drawn at `1.0` rather
+    /// than an infinite adjustment.
+    pub fn adjustment(&self, raw_width: f32, raw_height: f32) -> f32 {
+        let fit = |screen: f32, raw: f32| if raw > 0.0 { Some(screen / raw) } else { None };
+        let width_adjustment  = fit(screen_width(),  raw_width);
+        let height_adjustment = fit(screen_height(), raw_height);
+
+        let adjustment = match self.mode {
+            ScaleMode::FitWidth     => width_adjustment.unwrap_or(1.0),
+            ScaleMode::FitHeight    => height_adjustment.unwrap_or(1.0),
+            ScaleMode::Contain      => {
+                match (width_adjustment, height_adjustment) {
+                    (Some(width), Some(height)) => width.min(height),
+                    (Some(adjustment), None) | (None, Some(adjustment)) => adjustment,
+                    (None, None) => 1.0,
+                }
+            }
+            ScaleMode::Fixed(scale) => scale,
+        };
+
+        match self.max {
+            Some(max) => adjustment.min(max),
+            None => adjustment
+        }
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Scale::new(ScaleMode::FitWidth)
+    }
+}
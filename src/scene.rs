@@ -0,0 +1,111 @@
+/// Change to the `SceneStack` requested by the current `Scene`
+pub enum Transition {
+    /// Stay on the current scene
+    None,
+
+    /// Put a new scene on top of the current scene, such as a pause menu over the game
+    Push(Box<dyn Scene>),
+
+    /// Remove the current scene, returning to the scene underneath
+    Pop,
+
+    /// Replace the current scene, such as going from the main menu to the game
+    Replace(Box<dyn Scene>),
+}
+
+/// Screen of a game, such as the main menu, the game itself, or the game over screen, holding its
+/// own boards and rows
+pub trait Scene {
+    /// Called when the scene is added to the `SceneStack`
+    fn on_enter(&mut self) {}
+
+    /// Called when the scene is removed from the `SceneStack`
+    fn on_exit(&mut self) {}
+
+    /// Advance the scene by `dt` seconds, returning the change to make to the `SceneStack`
+    fn update(&mut self, dt: f32) -> Transition;
+
+    /// Draw the scene
+    fn draw(&self);
+}
+
+/// Stack of scenes where only the top scene is updated and drawn
+///
+/// ```
+/// let mut scenes = SceneStack::new(Box::new(MainMenu::new()));
+///
+/// loop {
+///     scenes.update(get_frame_time());
+///     if scenes.is_empty() {
+///         break;
+///     }
+///
+///     scenes.draw();
+///     next_frame().await
+/// }
+/// ```
+pub struct SceneStack {
+    /// Scenes from bottom to top
+    scenes: Vec<Box<dyn Scene>>,
+}
+
+impl SceneStack {
+    /// Create a new `SceneStack` starting at `initial`
+    pub fn new(initial: Box<dyn Scene>) -> Self {
+        let mut stack = SceneStack { scenes: Vec::new() };
+        stack.push(initial);
+        stack
+    }
+
+    /// Put `scene` on top of the current scene
+    pub fn push(&mut self, mut scene: Box<dyn Scene>) {
+        scene.on_enter();
+        self.scenes.push(scene);
+    }
+
+    /// Remove and return the current scene
+    pub fn pop(&mut self) -> Option<Box<dyn Scene>> {
+        let mut scene = self.scenes.pop()?;
+        scene.on_exit();
+        Some(scene)
+    }
+
+    /// Replace the current scene with `scene`, returning the replaced scene
+    pub fn replace(&mut self, scene: Box<dyn Scene>) -> Option<Box<dyn Scene>> {
+        let old = self.pop();
+        self.push(scene);
+        old
+    }
+
+    /// Returns `true` once every scene has been popped, such as when the game should quit
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    /// Get the number of scenes on the stack
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Update the current scene by `dt` seconds and apply its requested transition
+    pub fn update(&mut self, dt: f32) {
+        let transition = match self.scenes.last_mut() {
+            Some(scene) => scene.update(dt),
+            None => return
+        };
+
+        match transition {
+            Transition::None => {}
+            Transition::Push(scene) => self.push(scene),
+            Transition::Pop => { self.pop(); }
+            Transition::Replace(scene) => { self.replace(scene); }
+        }
+    }
+
+    /// Draw the current scene
+    pub fn draw(&self) {
+        if let Some(scene) = self.scenes.last() {
+            scene.draw();
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use macroquad::*;
+use crate::Resizeable;
+use crate::piece::Piece;
+
+/// Fraction of velocity and spin lost per second while sliding across the tray
+const FRICTION: f32 = 2.5;
+
+/// Fraction of velocity kept after bouncing off of a tray wall
+const RESTITUTION: f32 = 0.6;
+
+/// Speed (in pixels per second) under which a tossed piece is considered settled
+const SETTLE_SPEED: f32 = 5.0;
+
+/// `Piece` that has been thrown into a tray and slides, spins, and bounces off the tray walls
+/// until it settles. Used to make dice rolls and discards look thrown rather than animated.
+#[derive(Debug, Clone)]
+pub struct Toss {
+    /// Piece being tossed
+    piece: Piece,
+
+    /// Tray the piece is contained in, in screen coordinates
+    tray: Rect,
+
+    /// Resize adjustment the piece is drawn with
+    adjustment: f32,
+
+    /// Current top left position of the piece in screen coordinates
+    position: Vec2,
+
+    /// Current velocity in pixels per second
+    velocity: Vec2,
+
+    /// Current spin in radians per second
+    spin: f32,
+
+    /// Set once the piece has come to rest
+    settled: bool,
+}
+
+impl Toss {
+    /// Throw `piece` from `position` with the initial `velocity` and `spin` into the given `tray`
+    pub fn new(piece: Piece, tray: Rect, adjustment: f32, position: Vec2, velocity: Vec2,
+               spin: f32) -> Self {
+        Toss {
+            piece,
+            tray,
+            adjustment,
+            position,
+            velocity,
+            spin,
+            settled: false
+        }
+    }
+
+    /// Step the simulation forward by `dt` seconds
+    pub fn update(&mut self, dt: f32) {
+        if self.settled {
+            return;
+        }
+
+        // Move and spin the piece
+        self.position += self.velocity * dt;
+        self.piece.set_rotation(self.piece.rotation() + self.spin * dt);
+
+        // Apply friction to both the velocity and the spin
+        let damping = (1.0 - FRICTION * dt).max(0.0);
+        self.velocity *= damping;
+        self.spin *= damping;
+
+        // Bounce off of the tray walls
+        let width  = self.piece.width()  * self.adjustment;
+        let height = self.piece.height() * self.adjustment;
+
+        if self.position.x() < self.tray.x {
+            self.position.set_x(self.tray.x);
+            self.velocity.set_x(-self.velocity.x() * RESTITUTION);
+        } else if self.position.x() + width > self.tray.x + self.tray.w {
+            self.position.set_x(self.tray.x + self.tray.w - width);
+            self.velocity.set_x(-self.velocity.x() * RESTITUTION);
+        }
+
+        if self.position.y() < self.tray.y {
+            self.position.set_y(self.tray.y);
+            self.velocity.set_y(-self.velocity.y() * RESTITUTION);
+        } else if self.position.y() + height > self.tray.y + self.tray.h {
+            self.position.set_y(self.tray.y + self.tray.h - height);
+            self.velocity.set_y(-self.velocity.y() * RESTITUTION);
+        }
+
+        // Come to rest once the piece has slowed down enough
+        if self.velocity.length() < SETTLE_SPEED {
+            self.velocity = Vec2::zero();
+            self.spin = 0.0;
+            self.settled = true;
+        }
+    }
+
+    /// Returns `true` once the piece has come to rest in the tray
+    pub fn settled(&self) -> bool {
+        self.settled
+    }
+
+    /// Get the current top left position of the piece in screen coordinates
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Get the current rotation of the piece in radians
+    pub fn rotation(&self) -> f32 {
+        self.piece.rotation()
+    }
+
+    /// Get the piece being tossed
+    pub fn piece(&self) -> &Piece {
+        &self.piece
+    }
+
+    /// Draw the piece at its current position
+    pub fn draw(&self) {
+        self.piece.draw(self.position, self.adjustment);
+    }
+}